@@ -9,7 +9,7 @@ fn main() {
 
     // Determine the fixed update rate
     app.insert_resource(Time::<Fixed>::from_hz(100.0));
-    app.add_plugins((DefaultPlugins, DynamicsPlugin));
+    app.add_plugins((DefaultPlugins, DynamicsPlugin::default()));
 
     // Setup the visuals
     app.insert_resource(ClearColor(bevy_catppuccin::Flavor::MOCHA.base));
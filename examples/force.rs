@@ -2,7 +2,7 @@ use bevy::{
     pbr::{MeshMaterial3d, StandardMaterial},
     prelude::*,
 };
-use bevy_dynamics::{DynamicsPlugin, Mass, Velocity};
+use bevy_dynamics::{DynamicsPlugin, InverseMass, Mass, Velocity};
 
 mod common;
 use common::*;
@@ -57,7 +57,11 @@ fn main() {
     let mut app = App::new();
     // Determine the fixed update rate
     app.insert_resource(Time::<Fixed>::from_hz(TICK_RATE));
-    app.add_plugins((DefaultPlugins, MeshPickingPlugin, DynamicsPlugin));
+    app.add_plugins((
+        DefaultPlugins,
+        MeshPickingPlugin,
+        DynamicsPlugin::default(),
+    ));
 
     // Setup the visuals
     app.insert_resource(ClearColor(bevy_catppuccin::Flavor::MOCHA.base));
@@ -177,21 +181,21 @@ fn pointer_drag(
     );
 }
 
-/// Bevy [`Observer`] that applies the total force to the entity when the
+/// Bevy [`Observer`] that applies the dragged impulse to the entity when the
 /// pointer stops dragging on the plane.
 ///
 /// E.g. when the user stop holding the mouse button.
 fn pointer_drag_end(
     _trigger: Trigger<Pointer<DragEnd>>,
     mut force_arrow: ResMut<ForceArrow>,
-    mut query: Query<(&Mass, &mut Velocity)>,
+    mut query: Query<(&InverseMass, &mut Velocity)>,
 ) {
     let Some(vector) = force_arrow.vector() else {
         return;
     };
 
-    for (mass, mut velocity) in query.iter_mut() {
-        velocity.apply_impulse(vector * 50.0, &mass, 1.0 / TICK_RATE as f32);
+    for (inverse_mass, mut velocity) in query.iter_mut() {
+        velocity.apply_impulse(vector * 0.5, inverse_mass);
     }
 
     force_arrow.reset();
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::{Mass, Velocity};
+use super::{AngularAcceleration, Inertia, InverseMass, Velocity};
 
 /// Bevy [`Component`] representing an [`Entity`]'s acceleration.
 ///
@@ -20,8 +20,34 @@ impl Acceleration {
     }
 
     /// Apply a force to the [`Entity`] and update the acceleration.
-    pub fn apply_force(&mut self, force: Vec3, mass: &Mass) {
-        self.value += force / mass.value;
+    ///
+    /// The acceleration is persistent, so the force keeps acting until it is
+    /// removed again. Use [`crate::ExternalForce`] for forces that should only
+    /// act for a single step. An [`InverseMass`] of `0.0` leaves the
+    /// acceleration unchanged.
+    pub fn apply_force(&mut self, force: Vec3, inverse_mass: &InverseMass) {
+        self.value += force * inverse_mass.0;
+    }
+
+    /// Apply a force at a point in world space to the [`Entity`] and update
+    /// the acceleration and angular acceleration.
+    ///
+    /// The force is split into a linear part acting on the center of mass,
+    /// which is the translation of the `transform`, and a torque
+    /// `(world_point - center) × force` about the center of mass.
+    pub fn apply_force_at_point(
+        &mut self,
+        force: Vec3,
+        world_point: Vec3,
+        transform: &Transform,
+        inverse_mass: &InverseMass,
+        inertia: &Inertia,
+        angular_acceleration: &mut AngularAcceleration,
+    ) {
+        self.apply_force(force, inverse_mass);
+
+        let torque = (world_point - transform.translation).cross(force);
+        angular_acceleration.apply_torque(torque, inertia, transform.rotation);
     }
 
     /// Get the magnitude of the acceleration.
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+use super::{AngularVelocity, Inertia};
+
+/// Bevy [`Component`] representing an [`Entity`]'s angular acceleration.
+///
+/// This component requires the [`AngularVelocity`] component.
+#[derive(Component)]
+#[require(AngularVelocity)]
+pub struct AngularAcceleration {
+    /// The angular acceleration in world space, in radians per second
+    /// squared.
+    pub value: Vec3,
+}
+
+impl AngularAcceleration {
+    /// Create a new [`AngularAcceleration`] component with the given 3D
+    /// value.
+    pub const fn new(value: Vec3) -> Self {
+        Self { value }
+    }
+
+    /// Apply a torque to the [`Entity`] and update the angular acceleration.
+    ///
+    /// The angular acceleration is persistent, so the torque keeps acting
+    /// until it is removed again. Use [`crate::ExternalTorque`] for torques
+    /// that should only act for a single step.
+    pub fn apply_torque(
+        &mut self,
+        torque: Vec3,
+        inertia: &Inertia,
+        rotation: Quat,
+    ) {
+        self.value += inertia.inverse_world(rotation) * torque;
+    }
+
+    /// Get the magnitude of the angular acceleration.
+    pub fn magnitude(&self) -> f32 {
+        self.value.length()
+    }
+}
+
+impl Default for AngularAcceleration {
+    /// Create a new [`AngularAcceleration`] component with the default value
+    /// of `[0, 0, 0]`.
+    fn default() -> Self {
+        Self { value: Vec3::ZERO }
+    }
+}
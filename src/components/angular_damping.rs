@@ -0,0 +1,71 @@
+use bevy::{
+    ecs::{component::ComponentId, world::DeferredWorld},
+    prelude::*,
+};
+
+use super::{damping::validate_coefficient, DampingModel};
+
+/// Angular damping to simulate rotational friction
+///
+/// The coefficient must be finite and not negative. Invalid coefficients are
+/// clamped to `0.0` with a logged error when the component is inserted.
+#[derive(Component)]
+#[component(on_insert = validate_angular_damping)]
+pub struct AngularDamping {
+    /// Angular damping coefficient
+    pub value: f32,
+    /// The model used to apply the angular damping coefficient
+    pub model: DampingModel,
+}
+
+impl Default for AngularDamping {
+    /// Create a new [`AngularDamping`] component with the default value of
+    /// `0.0`.
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            model: DampingModel::default(),
+        }
+    }
+}
+
+impl AngularDamping {
+    /// Create a new [`AngularDamping`] component with an f32 value, using the
+    /// [`DampingModel::Exponential`] model.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            model: DampingModel::default(),
+        }
+    }
+
+    /// Set the [`DampingModel`] used to apply the angular damping
+    /// coefficient.
+    #[must_use]
+    pub const fn with_model(mut self, model: DampingModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Get the factor the angular speed is multiplied by after
+    /// `delta_time_secs` at the given `angular_speed`.
+    pub fn factor(&self, angular_speed: f32, delta_time_secs: f32) -> f32 {
+        self.model
+            .factor(self.value, angular_speed, delta_time_secs)
+    }
+}
+
+/// Validate an [`AngularDamping`] when it is inserted.
+fn validate_angular_damping(
+    mut world: DeferredWorld,
+    entity: Entity,
+    _: ComponentId,
+) {
+    if let Some(mut angular_damping) = world.get_mut::<AngularDamping>(entity) {
+        validate_coefficient(
+            &mut angular_damping.value,
+            "angular damping",
+            entity,
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+use super::{AngularAcceleration, AngularDamping, Velocity};
+
+/// Bevy [`Component`] representing an [`Entity`]'s angular velocity.
+/// This component requires the [`Velocity`] and [`AngularDamping`]
+/// components, thus also requiring the [`Transform`] component.
+#[derive(Component)]
+#[require(Velocity, AngularDamping)]
+pub struct AngularVelocity {
+    /// The angular velocity in world space, as an axis scaled by the angular
+    /// speed in radians per second.
+    pub value: Vec3,
+}
+
+impl AngularVelocity {
+    /// Create a new [`AngularVelocity`] component with the given 3D value.
+    pub const fn new(value: Vec3) -> Self {
+        Self { value }
+    }
+
+    /// Apply an angular acceleration to the angular velocity.
+    pub fn apply_angular_acceleration(
+        &mut self,
+        angular_acceleration: &AngularAcceleration,
+        delta_time_secs: f32,
+    ) {
+        self.value += angular_acceleration.value * delta_time_secs;
+    }
+
+    /// Apply angular damping to the angular velocity to mimic friction.
+    ///
+    /// The angular speed is reduced according to the [`crate::DampingModel`]
+    /// of the [`AngularDamping`].
+    pub fn apply_angular_damping(
+        &mut self,
+        angular_damping: &AngularDamping,
+        delta_time_secs: f32,
+    ) {
+        self.value *=
+            angular_damping.factor(self.angular_speed(), delta_time_secs);
+    }
+
+    /// Integrate the `rotation` by the angular velocity over
+    /// `delta_time_secs`.
+    ///
+    /// Uses the quaternion derivative `q' = ½ ω q` and renormalises the
+    /// result to keep it a valid rotation.
+    pub fn integrate_rotation(
+        &self,
+        rotation: &mut Quat,
+        delta_time_secs: f32,
+    ) {
+        let omega =
+            Quat::from_xyzw(self.value.x, self.value.y, self.value.z, 0.0);
+        let derivative = omega * *rotation * (0.5 * delta_time_secs);
+        *rotation = (*rotation + derivative).normalize();
+    }
+
+    /// Get the current angular speed in radians per second.
+    pub fn angular_speed(&self) -> f32 {
+        self.value.length()
+    }
+}
+
+impl Default for AngularVelocity {
+    /// Create a new [`AngularVelocity`] component with the default value of
+    /// `[0, 0, 0]`.
+    fn default() -> Self {
+        Self { value: Vec3::ZERO }
+    }
+}
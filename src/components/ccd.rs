@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use super::Velocity;
+
+/// Bevy [`Component`] enabling continuous collision detection for a fast
+/// moving [`Entity`].
+///
+/// The [`Entity`] is swept as a sphere from its position at the start of the
+/// step to its position after integration, against every static
+/// [`crate::Collider`]. On impact it is moved back to the time of impact and
+/// its [`Velocity`] is reflected, instead of tunneling through thin walls.
+///
+/// The swept sphere uses the [`crate::Collider::bounding_radius`] of the
+/// [`Entity`], or a point if it has no [`crate::Collider`].
+///
+/// This component requires the [`Velocity`] component.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[require(Velocity)]
+pub struct Ccd {
+    /// The translation at the start of the current step.
+    pub(crate) start: Vec3,
+}
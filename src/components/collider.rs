@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+
+/// Bevy [`Component`] describing the collision shape of an [`Entity`].
+///
+/// The shape is positioned and oriented by the [`Transform`] of the
+/// [`Entity`], while its scale is ignored. Entities with a [`Collider`] but
+/// without a [`crate::Velocity`] are treated as static and immovable.
+///
+/// This component requires the [`Transform`] component.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(Transform)]
+pub enum Collider {
+    /// A sphere centered on the [`Entity`].
+    Sphere {
+        /// The radius of the sphere.
+        radius: f32,
+    },
+    /// A box centered on the [`Entity`].
+    Cuboid {
+        /// Half the size of the box along each local axis.
+        half_extents: Vec3,
+    },
+    /// A capsule centered on the [`Entity`], aligned with its local y-axis.
+    Capsule {
+        /// The radius of the capsule.
+        radius: f32,
+        /// Half the length of the segment between the centers of the two
+        /// hemispheres.
+        half_height: f32,
+    },
+    /// An infinite half-space, bounded by the plane through the [`Entity`]'s
+    /// translation. Everything behind the plane is solid.
+    HalfSpace {
+        /// The outward normal of the plane in the local space of the
+        /// [`Entity`].
+        normal: Dir3,
+    },
+}
+
+impl Collider {
+    /// Create a new sphere [`Collider`] with the given radius.
+    pub const fn sphere(radius: f32) -> Self {
+        Self::Sphere { radius }
+    }
+
+    /// Create a new box [`Collider`] with the given half extents.
+    pub const fn cuboid(half_extents: Vec3) -> Self {
+        Self::Cuboid { half_extents }
+    }
+
+    /// Create a new capsule [`Collider`] along the local y-axis with the given
+    /// radius and half height.
+    pub const fn capsule(radius: f32, half_height: f32) -> Self {
+        Self::Capsule {
+            radius,
+            half_height,
+        }
+    }
+
+    /// Create a new half-space [`Collider`] with the given outward normal.
+    pub const fn half_space(normal: Dir3) -> Self {
+        Self::HalfSpace { normal }
+    }
+
+    /// Get the radius of a sphere around the [`Entity`]'s translation that
+    /// contains the whole shape. Infinite for a half-space.
+    pub fn bounding_radius(&self) -> f32 {
+        match self {
+            Self::Sphere { radius } => *radius,
+            Self::Cuboid { half_extents } => half_extents.length(),
+            Self::Capsule {
+                radius,
+                half_height,
+            } => radius + half_height,
+            Self::HalfSpace { .. } => f32::INFINITY,
+        }
+    }
+
+    /// Get the minimum and maximum corners of the world space axis aligned box
+    /// containing the whole shape. Infinite for a half-space.
+    pub fn aabb(&self, transform: &Transform) -> (Vec3, Vec3) {
+        let extents = match self {
+            Self::Sphere { radius } => Vec3::splat(*radius),
+            Self::Cuboid { half_extents } => {
+                Mat3::from_quat(transform.rotation).abs() * *half_extents
+            }
+            Self::Capsule {
+                radius,
+                half_height,
+            } => {
+                (transform.rotation * Vec3::Y * *half_height).abs()
+                    + Vec3::splat(*radius)
+            }
+            Self::HalfSpace { .. } => Vec3::INFINITY,
+        };
+        (
+            transform.translation - extents,
+            transform.translation + extents,
+        )
+    }
+
+    /// Get the world space segment and radius of a sphere or capsule, the
+    /// shape being all points within the radius of the segment.
+    pub(crate) fn rounded_segment(
+        &self,
+        transform: &Transform,
+    ) -> Option<(Vec3, Vec3, f32)> {
+        match self {
+            Self::Sphere { radius } => {
+                Some((transform.translation, transform.translation, *radius))
+            }
+            Self::Capsule {
+                radius,
+                half_height,
+            } => {
+                let offset = transform.rotation * Vec3::Y * *half_height;
+                Some((
+                    transform.translation - offset,
+                    transform.translation + offset,
+                    *radius,
+                ))
+            }
+            Self::Cuboid { .. } | Self::HalfSpace { .. } => None,
+        }
+    }
+}
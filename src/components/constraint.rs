@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+/// The kind of a [`Constraint`], and the entities it acts on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConstraintKind {
+    /// Keeps two entities at exactly `length` from each other, like a rigid
+    /// rod.
+    Distance {
+        /// The first [`Entity`].
+        first: Entity,
+        /// The second [`Entity`].
+        second: Entity,
+        /// The distance to keep between both entities.
+        length: f32,
+    },
+    /// Keeps two entities at most `length` from each other, like a rope.
+    MaxDistance {
+        /// The first [`Entity`].
+        first: Entity,
+        /// The second [`Entity`].
+        second: Entity,
+        /// The maximum distance between both entities.
+        length: f32,
+    },
+    /// Pins an [`Entity`] to a fixed point in world space.
+    Attachment {
+        /// The attached [`Entity`].
+        entity: Entity,
+        /// The point in world space the [`Entity`] is attached to.
+        point: Vec3,
+    },
+    /// Keeps an [`Entity`] on the side of a plane its `normal` points to.
+    Plane {
+        /// The constrained [`Entity`].
+        entity: Entity,
+        /// The normal of the plane.
+        normal: Dir3,
+        /// The signed distance of the plane from the origin along the
+        /// `normal`.
+        distance: f32,
+    },
+}
+
+/// Bevy [`Component`] holding a position based constraint, solved with
+/// extended position based dynamics (XPBD).
+///
+/// The constraint is spawned as its own [`Entity`], and is solved after the
+/// dynamics have been integrated by moving the positions of the constrained
+/// entities directly, in proportion to their inverse [`crate::Mass`]. The
+/// [`crate::Velocity`] of each [`Entity`] is corrected to match the change in
+/// position. Entities without a [`crate::Velocity`] are treated as static, so
+/// a chain can be hung from a static [`Entity`].
+///
+/// Unlike a [`crate::Spring`], constraints stay stable at any stiffness, which
+/// makes them suited for ropes, chains and soft linkages. The `compliance` is
+/// the inverse of the stiffness, where `0.0` is perfectly rigid.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Constraint {
+    /// The kind of constraint and the entities it acts on.
+    pub kind: ConstraintKind,
+    /// The inverse stiffness of the constraint, in meters per newton.
+    pub compliance: f32,
+}
+
+impl Constraint {
+    /// Create a new rigid [`ConstraintKind::Distance`] [`Constraint`].
+    pub const fn distance(first: Entity, second: Entity, length: f32) -> Self {
+        Self::new(ConstraintKind::Distance {
+            first,
+            second,
+            length,
+        })
+    }
+
+    /// Create a new rigid [`ConstraintKind::MaxDistance`] [`Constraint`].
+    pub const fn max_distance(
+        first: Entity,
+        second: Entity,
+        length: f32,
+    ) -> Self {
+        Self::new(ConstraintKind::MaxDistance {
+            first,
+            second,
+            length,
+        })
+    }
+
+    /// Create a new rigid [`ConstraintKind::Attachment`] [`Constraint`].
+    pub const fn attachment(entity: Entity, point: Vec3) -> Self {
+        Self::new(ConstraintKind::Attachment { entity, point })
+    }
+
+    /// Create a new rigid [`ConstraintKind::Plane`] [`Constraint`].
+    pub const fn plane(entity: Entity, normal: Dir3, distance: f32) -> Self {
+        Self::new(ConstraintKind::Plane {
+            entity,
+            normal,
+            distance,
+        })
+    }
+
+    /// Create a new rigid [`Constraint`] of the given kind.
+    pub const fn new(kind: ConstraintKind) -> Self {
+        Self {
+            kind,
+            compliance: 0.0,
+        }
+    }
+
+    /// Set the compliance of the [`Constraint`].
+    #[must_use]
+    pub const fn with_compliance(mut self, compliance: f32) -> Self {
+        self.compliance = compliance;
+        self
+    }
+}
@@ -1,22 +1,110 @@
-use bevy::prelude::*;
+use bevy::{
+    ecs::{component::ComponentId, world::DeferredWorld},
+    prelude::*,
+};
+
+/// The model used to turn a damping coefficient into a reduction of speed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DampingModel {
+    /// Exact exponential decay `v *= exp(-c·dt)`. Stable for any coefficient
+    /// and gives the same result regardless of the tick rate.
+    #[default]
+    Exponential,
+    /// Linear decay `v *= 1 - c·dt`, clamped so it never reverses the
+    /// direction of motion. Only matches [`DampingModel::Exponential`] for
+    /// small `c·dt`.
+    Linear,
+    /// Quadratic decay like air drag, where the deceleration is proportional
+    /// to the square of the speed, `dv/dt = -c·|v|·v`. Integrated exactly as
+    /// `v *= 1 / (1 + c·|v|·dt)`.
+    Quadratic,
+}
+
+impl DampingModel {
+    /// Get the factor the speed is multiplied by after `delta_time_secs` with
+    /// the given damping `coefficient` and current `speed`.
+    pub fn factor(
+        self,
+        coefficient: f32,
+        speed: f32,
+        delta_time_secs: f32,
+    ) -> f32 {
+        match self {
+            Self::Exponential => (-coefficient * delta_time_secs).exp(),
+            Self::Linear => coefficient.mul_add(-delta_time_secs, 1.0).max(0.0),
+            Self::Quadratic => {
+                (coefficient * speed).mul_add(delta_time_secs, 1.0).recip()
+            }
+        }
+    }
+}
+
+/// Clamp an invalid damping `coefficient` to `0.0`, logging an error naming
+/// the `component` of the given [`Entity`].
+pub(super) fn validate_coefficient(
+    coefficient: &mut f32,
+    component: &str,
+    entity: Entity,
+) {
+    if !coefficient.is_finite() || *coefficient < 0.0 {
+        error!(
+            "Invalid {component} coefficient {coefficient} on {entity}, \
+             clamping it to 0.0"
+        );
+        *coefficient = 0.0;
+    }
+}
 
 /// Damping to simulate friction
+///
+/// The coefficient must be finite and not negative. Invalid coefficients are
+/// clamped to `0.0` with a logged error when the component is inserted.
 #[derive(Component)]
+#[component(on_insert = validate_damping)]
 pub struct Damping {
     /// Damping coefficient
     pub value: f32,
+    /// The model used to apply the damping coefficient
+    pub model: DampingModel,
 }
 
 impl Default for Damping {
     /// Create a new [`Damping`] component with the default value of `0.0`.
     fn default() -> Self {
-        Self { value: 0.0 }
+        Self {
+            value: 0.0,
+            model: DampingModel::default(),
+        }
     }
 }
 
 impl Damping {
-    /// Create a new [`Damping`] component with an f32 value.
+    /// Create a new [`Damping`] component with an f32 value, using the
+    /// [`DampingModel::Exponential`] model.
     pub fn new(value: f32) -> Self {
-        Self { value }
+        Self {
+            value,
+            model: DampingModel::default(),
+        }
+    }
+
+    /// Set the [`DampingModel`] used to apply the damping coefficient.
+    #[must_use]
+    pub const fn with_model(mut self, model: DampingModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Get the factor the speed is multiplied by after `delta_time_secs` at
+    /// the given `speed`.
+    pub fn factor(&self, speed: f32, delta_time_secs: f32) -> f32 {
+        self.model.factor(self.value, speed, delta_time_secs)
+    }
+}
+
+/// Validate a [`Damping`] when it is inserted.
+fn validate_damping(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    if let Some(mut damping) = world.get_mut::<Damping>(entity) {
+        validate_coefficient(&mut damping.value, "damping", entity);
     }
 }
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use super::{InverseMass, Mass};
+
+/// Physical description of the fluid an [`Entity`] moves through, used by
+/// [`Drag`] to specify the quadratic term as `½·ρ·C_d·A`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fluid {
+    /// Density of the fluid `ρ`, in kilograms per cubic meter.
+    pub density: f32,
+    /// Reference area `A` facing each local axis, in square meters.
+    pub reference_area: Vec3,
+}
+
+impl Fluid {
+    /// Density of air at sea level and 15 °C, in kilograms per cubic meter.
+    pub const AIR_DENSITY: f32 = 1.225;
+    /// Density of fresh water at 4 °C, in kilograms per cubic meter.
+    pub const WATER_DENSITY: f32 = 1000.0;
+
+    /// Create a new [`Fluid`] with the given density and the same reference
+    /// area facing every axis.
+    pub const fn new(density: f32, reference_area: f32) -> Self {
+        Self {
+            density,
+            reference_area: Vec3::splat(reference_area),
+        }
+    }
+}
+
+/// Bevy [`Component`] applying linear and quadratic drag forces to an
+/// [`Entity`].
+///
+/// The coefficients are given per local axis of the [`Entity`], so a body can
+/// have low drag moving forward and high drag moving sideways. The drag force
+/// in local space is `-(linear·v + quadratic·|v|·v)`, converted to
+/// acceleration using the [`InverseMass`] of the [`Entity`].
+///
+/// This component requires the [`Mass`] component.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(Mass)]
+pub struct Drag {
+    /// Linear drag coefficients per local axis, in kilograms per second.
+    pub linear: Vec3,
+    /// Quadratic drag coefficients per local axis, in kilograms per meter.
+    /// When a [`Fluid`] is set these are the dimensionless drag coefficients
+    /// `C_d` instead.
+    pub quadratic: Vec3,
+    /// The fluid the [`Entity`] moves through, if the quadratic term is
+    /// specified in physical terms.
+    pub fluid: Option<Fluid>,
+}
+
+impl Default for Drag {
+    /// Create a new [`Drag`] component without any drag.
+    fn default() -> Self {
+        Self {
+            linear: Vec3::ZERO,
+            quadratic: Vec3::ZERO,
+            fluid: None,
+        }
+    }
+}
+
+impl Drag {
+    /// Create a new [`Drag`] component with the same linear and quadratic
+    /// coefficients on every axis.
+    pub const fn new(linear: f32, quadratic: f32) -> Self {
+        Self::anisotropic(Vec3::splat(linear), Vec3::splat(quadratic))
+    }
+
+    /// Create a new [`Drag`] component with per local axis linear and
+    /// quadratic coefficients.
+    pub const fn anisotropic(linear: Vec3, quadratic: Vec3) -> Self {
+        Self {
+            linear,
+            quadratic,
+            fluid: None,
+        }
+    }
+
+    /// Create a new [`Drag`] component from the drag coefficient `C_d` per
+    /// local axis and the [`Fluid`] the [`Entity`] moves through.
+    pub const fn from_fluid(drag_coefficient: Vec3, fluid: Fluid) -> Self {
+        Self {
+            linear: Vec3::ZERO,
+            quadratic: drag_coefficient,
+            fluid: Some(fluid),
+        }
+    }
+
+    /// Get the effective quadratic coefficients per local axis, in kilograms
+    /// per meter.
+    pub fn quadratic_coefficients(&self) -> Vec3 {
+        self.fluid.map_or(self.quadratic, |fluid| {
+            0.5 * fluid.density * self.quadratic * fluid.reference_area
+        })
+    }
+
+    /// Get the drag force in world space for the given world space `velocity`
+    /// and the `rotation` of the [`Entity`].
+    pub fn force(&self, velocity: Vec3, rotation: Quat) -> Vec3 {
+        let local_velocity = rotation.inverse() * velocity;
+        let local_force = -(self.linear * local_velocity
+            + self.quadratic_coefficients()
+                * local_velocity.length()
+                * local_velocity);
+        rotation * local_force
+    }
+
+    /// Get the acceleration caused by the drag force for the given world
+    /// space `velocity` and the `rotation` of the [`Entity`].
+    pub fn acceleration(
+        &self,
+        velocity: Vec3,
+        rotation: Quat,
+        inverse_mass: &InverseMass,
+    ) -> Vec3 {
+        self.force(velocity, rotation) * inverse_mass.0
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+use super::{ExternalTorque, InverseMass, Mass};
+
+/// Bevy [`Component`] accumulating the forces applied to an [`Entity`] during
+/// a single step.
+///
+/// Systems add forces with [`ExternalForce::apply_force`] before the dynamics
+/// are applied, in the [`crate::DynamicsSet::ApplyForces`] set. The total
+/// force is converted to acceleration using the [`InverseMass`] of the
+/// [`Entity`], and is cleared after integration. Use [`crate::Acceleration`]
+/// for constant terms that should keep acting.
+///
+/// This component requires the [`Mass`] component.
+#[derive(Component)]
+#[require(Mass)]
+pub struct ExternalForce {
+    /// The force accumulated during the current step, in newtons.
+    pub value: Vec3,
+}
+
+impl ExternalForce {
+    /// Create a new [`ExternalForce`] component with the given 3D value.
+    pub const fn new(value: Vec3) -> Self {
+        Self { value }
+    }
+
+    /// Add a force to the accumulator for the current step.
+    pub fn apply_force(&mut self, force: Vec3) {
+        self.value += force;
+    }
+
+    /// Add a force applied at a point in world space to the accumulators for
+    /// the current step.
+    ///
+    /// The force is split into a linear part acting on the `center_of_mass`,
+    /// and a torque `(world_point - center_of_mass) × force` which is added to
+    /// the [`ExternalTorque`].
+    pub fn apply_force_at_point(
+        &mut self,
+        force: Vec3,
+        world_point: Vec3,
+        center_of_mass: Vec3,
+        external_torque: &mut ExternalTorque,
+    ) {
+        self.apply_force(force);
+        external_torque
+            .apply_torque((world_point - center_of_mass).cross(force));
+    }
+
+    /// Get the acceleration resulting from the accumulated force.
+    pub fn acceleration(&self, inverse_mass: &InverseMass) -> Vec3 {
+        self.value * inverse_mass.0
+    }
+
+    /// Clear the accumulated force.
+    pub const fn clear(&mut self) {
+        self.value = Vec3::ZERO;
+    }
+}
+
+impl Default for ExternalForce {
+    /// Create a new [`ExternalForce`] component with the default value of `[0,
+    /// 0, 0]`.
+    fn default() -> Self {
+        Self { value: Vec3::ZERO }
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use super::{InverseMass, Mass, Velocity};
+
+/// Bevy [`Component`] accumulating the impulses applied to an [`Entity`]
+/// during a single step.
+///
+/// Systems add impulses with [`ExternalImpulse::apply_impulse`]. The total
+/// impulse is applied to the [`Velocity`] once per fixed step using the
+/// [`InverseMass`] of the [`Entity`], and is cleared afterwards.
+///
+/// This component requires the [`Mass`] component.
+#[derive(Component)]
+#[require(Mass)]
+pub struct ExternalImpulse {
+    /// The impulse accumulated during the current step, in newton-seconds.
+    pub value: Vec3,
+}
+
+impl ExternalImpulse {
+    /// Create a new [`ExternalImpulse`] component with the given 3D value.
+    pub const fn new(value: Vec3) -> Self {
+        Self { value }
+    }
+
+    /// Add an impulse to the accumulator for the current step.
+    pub fn apply_impulse(&mut self, impulse: Vec3) {
+        self.value += impulse;
+    }
+
+    /// Apply the accumulated impulse to the [`Velocity`] and clear it.
+    pub fn consume(
+        &mut self,
+        velocity: &mut Velocity,
+        inverse_mass: &InverseMass,
+    ) {
+        velocity.value += self.value * inverse_mass.0;
+        self.value = Vec3::ZERO;
+    }
+}
+
+impl Default for ExternalImpulse {
+    /// Create a new [`ExternalImpulse`] component with the default value of
+    /// `[0, 0, 0]`.
+    fn default() -> Self {
+        Self { value: Vec3::ZERO }
+    }
+}
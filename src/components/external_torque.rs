@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use super::Inertia;
+
+/// Bevy [`Component`] accumulating the torques applied to an [`Entity`]
+/// during a single step.
+///
+/// Systems add torques with [`ExternalTorque::apply_torque`] before the
+/// dynamics are applied. The total torque is converted to angular
+/// acceleration using the [`Inertia`] of the [`Entity`], and is cleared after
+/// integration.
+///
+/// This component requires the [`Inertia`] component.
+#[derive(Component)]
+#[require(Inertia)]
+pub struct ExternalTorque {
+    /// The torque accumulated during the current step in world space, in
+    /// newton meters.
+    pub value: Vec3,
+}
+
+impl ExternalTorque {
+    /// Create a new [`ExternalTorque`] component with the given 3D value.
+    pub const fn new(value: Vec3) -> Self {
+        Self { value }
+    }
+
+    /// Add a torque to the accumulator for the current step.
+    pub fn apply_torque(&mut self, torque: Vec3) {
+        self.value += torque;
+    }
+
+    /// Clear the accumulated torque.
+    pub const fn clear(&mut self) {
+        self.value = Vec3::ZERO;
+    }
+}
+
+impl Default for ExternalTorque {
+    /// Create a new [`ExternalTorque`] component with the default value of
+    /// `[0, 0, 0]`.
+    fn default() -> Self {
+        Self { value: Vec3::ZERO }
+    }
+}
@@ -0,0 +1,45 @@
+use std::{fmt, sync::Arc};
+
+use bevy::prelude::*;
+
+use super::Mass;
+
+/// The function of a [`ForceField`], giving the force for a position and
+/// velocity.
+type FieldFn = dyn Fn(Vec3, Vec3) -> Vec3 + Send + Sync;
+
+/// Bevy [`Component`] applying a force that depends on the position and
+/// velocity of an [`Entity`], such as the attraction of a planet.
+///
+/// Unlike an [`crate::ExternalForce`], the field is evaluated by the
+/// [`crate::Integrator`] at every intermediate state of the step, so the
+/// higher order schemes keep their accuracy in orbital scenes. The force is
+/// converted to acceleration using the [`crate::InverseMass`] of the
+/// [`Entity`].
+///
+/// This component requires the [`Mass`] component.
+#[derive(Component, Clone)]
+#[require(Mass)]
+pub struct ForceField(Arc<FieldFn>);
+
+impl ForceField {
+    /// Create a new [`ForceField`] component from a function giving the force
+    /// in world space for a world space position and velocity.
+    pub fn new(
+        field: impl Fn(Vec3, Vec3) -> Vec3 + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(field))
+    }
+
+    /// Get the force of the field at `position` for an [`Entity`] moving at
+    /// `velocity`.
+    pub fn force(&self, position: Vec3, velocity: Vec3) -> Vec3 {
+        (self.0)(position, velocity)
+    }
+}
+
+impl fmt::Debug for ForceField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ForceField").finish_non_exhaustive()
+    }
+}
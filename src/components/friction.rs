@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+/// Bevy [`Component`] determining the Coulomb friction of the contacts of a
+/// [`crate::Collider`].
+///
+/// The friction of a contact is the average of both colliders, and limits the
+/// tangential impulse to the friction times the normal impulse.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Friction {
+    /// Coefficient of friction
+    pub value: f32,
+}
+
+impl Default for Friction {
+    /// Create a new [`Friction`] component with the default value of `0.5`.
+    fn default() -> Self {
+        Self { value: 0.5 }
+    }
+}
+
+impl Friction {
+    /// Create a new [`Friction`] component with an f32 value.
+    pub const fn new(value: f32) -> Self {
+        Self { value }
+    }
+}
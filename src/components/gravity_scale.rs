@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// Bevy [`Component`] scaling the [`crate::Gravity`] applied to an [`Entity`].
+///
+/// A scale of `0.0` opts the [`Entity`] out of gravity, and a negative scale
+/// makes it float upwards. Entities without this component use a scale of
+/// `1.0`.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GravityScale(pub f32);
+
+impl Default for GravityScale {
+    /// Create a new [`GravityScale`] component with the default value of
+    /// `1.0`.
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+use super::AngularAcceleration;
+
+/// Bevy [`Component`] representing an [`Entity`]'s moment of inertia.
+///
+/// This component requires the [`AngularAcceleration`] component, thus also
+/// requiring the [`crate::AngularVelocity`], [`crate::AngularDamping`] and
+/// [`Transform`] components.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(AngularAcceleration)]
+pub enum Inertia {
+    /// The same moment of inertia about every axis, in kilogram square
+    /// meters.
+    Scalar(f32),
+    /// The full inertia tensor in the local space of the [`Entity`], in
+    /// kilogram square meters.
+    Tensor(Mat3),
+}
+
+impl Default for Inertia {
+    /// Create a new [`Inertia`] component with the default value of
+    /// `Scalar(1.0)`.
+    fn default() -> Self {
+        Self::Scalar(1.0)
+    }
+}
+
+impl Inertia {
+    /// Create the [`Inertia`] of a solid sphere with the given `mass` and
+    /// `radius`.
+    pub fn solid_sphere(mass: f32, radius: f32) -> Self {
+        Self::Scalar(0.4 * mass * radius * radius)
+    }
+
+    /// Create the [`Inertia`] of a solid cuboid with the given `mass` and
+    /// `half_extents`.
+    pub fn solid_cuboid(mass: f32, half_extents: Vec3) -> Self {
+        let size = half_extents * 2.0;
+        let squared = size * size;
+        Self::Tensor(Mat3::from_diagonal(
+            mass / 12.0
+                * Vec3::new(
+                    squared.y + squared.z,
+                    squared.x + squared.z,
+                    squared.x + squared.y,
+                ),
+        ))
+    }
+
+    /// Get the inertia tensor in world space for the given `rotation`.
+    pub fn world(&self, rotation: Quat) -> Mat3 {
+        match self {
+            Self::Scalar(value) => Mat3::from_diagonal(Vec3::splat(*value)),
+            Self::Tensor(tensor) => {
+                let rotation = Mat3::from_quat(rotation);
+                rotation * *tensor * rotation.transpose()
+            }
+        }
+    }
+
+    /// Get the inverse inertia tensor in world space for the given
+    /// `rotation`.
+    pub fn inverse_world(&self, rotation: Quat) -> Mat3 {
+        match self {
+            Self::Scalar(value) => {
+                Mat3::from_diagonal(Vec3::splat(value.recip()))
+            }
+            Self::Tensor(tensor) => {
+                let rotation = Mat3::from_quat(rotation);
+                rotation * tensor.inverse() * rotation.transpose()
+            }
+        }
+    }
+}
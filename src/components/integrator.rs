@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+
+/// The numerical integration scheme used to advance an [`Entity`]'s position
+/// and velocity over a single step.
+///
+/// Set in the [`crate::DynamicsConfig`] to select the scheme for every
+/// [`Entity`], and can be added as a [`Component`] to override the scheme for
+/// a single [`Entity`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Integrator {
+    /// Explicit (forward) Euler. Position is advanced with the velocity from
+    /// the start of the step. Cheapest, but gains energy over time.
+    ExplicitEuler,
+    /// Semi-implicit (symplectic) Euler. Velocity is advanced first, and the
+    /// new velocity is used to advance the position. Cheap and stable enough
+    /// for most scenes.
+    #[default]
+    SemiImplicitEuler,
+    /// Velocity Verlet. Second order and symplectic, good for orbital scenes
+    /// where energy should be conserved.
+    VelocityVerlet,
+    /// Leapfrog in drift-kick-drift form. Second order and symplectic, with
+    /// the acceleration sampled at the middle of the step.
+    Leapfrog,
+    /// Classic fourth order Runge-Kutta. Most accurate per step, at the cost
+    /// of four acceleration evaluations.
+    Rk4,
+}
+
+impl Integrator {
+    /// Advance `position` and `velocity` by `delta_time_secs` using this
+    /// scheme.
+    ///
+    /// The `acceleration` closure is evaluated with a position and velocity,
+    /// and returns the acceleration at that state.
+    pub fn step(
+        self,
+        position: &mut Vec3,
+        velocity: &mut Vec3,
+        delta_time_secs: f32,
+        acceleration: impl Fn(Vec3, Vec3) -> Vec3,
+    ) {
+        let dt = delta_time_secs;
+
+        match self {
+            Self::ExplicitEuler => {
+                let a = acceleration(*position, *velocity);
+                *position += *velocity * dt;
+                *velocity += a * dt;
+            }
+            Self::SemiImplicitEuler => {
+                *velocity += acceleration(*position, *velocity) * dt;
+                *position += *velocity * dt;
+            }
+            Self::VelocityVerlet => {
+                let a0 = acceleration(*position, *velocity);
+                *position += *velocity * dt + 0.5 * a0 * dt * dt;
+                let half_velocity = *velocity + 0.5 * a0 * dt;
+                let a1 = acceleration(*position, half_velocity);
+                *velocity = half_velocity + 0.5 * a1 * dt;
+            }
+            Self::Leapfrog => {
+                let half_position = *position + 0.5 * *velocity * dt;
+                *velocity += acceleration(half_position, *velocity) * dt;
+                *position = half_position + 0.5 * *velocity * dt;
+            }
+            Self::Rk4 => {
+                let (x0, v0) = (*position, *velocity);
+
+                let k1_x = v0;
+                let k1_v = acceleration(x0, v0);
+
+                let k2_x = v0 + 0.5 * dt * k1_v;
+                let k2_v = acceleration(x0 + 0.5 * dt * k1_x, k2_x);
+
+                let k3_x = v0 + 0.5 * dt * k2_v;
+                let k3_v = acceleration(x0 + 0.5 * dt * k2_x, k3_x);
+
+                let k4_x = v0 + dt * k3_v;
+                let k4_v = acceleration(x0 + dt * k3_x, k4_x);
+
+                *position =
+                    x0 + dt / 6.0 * (k1_x + 2.0 * k2_x + 2.0 * k3_x + k4_x);
+                *velocity =
+                    v0 + dt / 6.0 * (k1_v + 2.0 * k2_v + 2.0 * k3_v + k4_v);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The fixed step used by the tests, in seconds.
+    const DELTA_TIME_SECS: f32 = 1.0 / 60.0;
+
+    /// The number of steps simulated by the tests, covering ten seconds.
+    const STEPS: u32 = 600;
+
+    /// Integrate from `position` and `velocity` over [`STEPS`] steps, and
+    /// return the final position.
+    fn simulate(
+        integrator: Integrator,
+        mut position: Vec3,
+        mut velocity: Vec3,
+        acceleration: impl Fn(Vec3, Vec3) -> Vec3,
+    ) -> Vec3 {
+        for _ in 0..STEPS {
+            integrator.step(
+                &mut position,
+                &mut velocity,
+                DELTA_TIME_SECS,
+                &acceleration,
+            );
+        }
+        position
+    }
+
+    /// The maximum error of each scheme on the harmonic oscillator after ten
+    /// seconds.
+    const HARMONIC_ERRORS: [(Integrator, f32); 5] = [
+        (Integrator::ExplicitEuler, 1e-1),
+        (Integrator::SemiImplicitEuler, 5e-3),
+        (Integrator::VelocityVerlet, 1e-4),
+        (Integrator::Leapfrog, 1e-4),
+        (Integrator::Rk4, 5e-6),
+    ];
+
+    #[test]
+    fn harmonic_oscillator_drift() {
+        // x'' = -x starting at rest at x = 1, so x(t) = cos(t)
+        #[allow(clippy::cast_precision_loss)]
+        let expected = (STEPS as f32 * DELTA_TIME_SECS).cos();
+
+        for (integrator, max_error) in HARMONIC_ERRORS {
+            let position =
+                simulate(integrator, Vec3::X, Vec3::ZERO, |position, _| {
+                    -position
+                });
+            let error = (position.x - expected).abs();
+            assert!(
+                error < max_error,
+                "{integrator:?} drifted by {error}, more than {max_error}"
+            );
+        }
+    }
+
+    /// The maximum error of each scheme under constant gravity after ten
+    /// seconds. The second order schemes are exact up to rounding errors.
+    const GRAVITY_ERRORS: [(Integrator, f32); 5] = [
+        (Integrator::ExplicitEuler, 1.0),
+        (Integrator::SemiImplicitEuler, 1.0),
+        (Integrator::VelocityVerlet, 1e-3),
+        (Integrator::Leapfrog, 1e-3),
+        (Integrator::Rk4, 1e-3),
+    ];
+
+    #[test]
+    fn constant_gravity_drift() {
+        // x'' = g starting with an upwards velocity, so
+        // x(t) = v t + g t² / 2
+        let gravity = Vec3::new(0.0, -9.81, 0.0);
+        let velocity = Vec3::new(1.0, 20.0, 0.0);
+        #[allow(clippy::cast_precision_loss)]
+        let time = STEPS as f32 * DELTA_TIME_SECS;
+        let expected = velocity * time + 0.5 * gravity * time * time;
+
+        for (integrator, max_error) in GRAVITY_ERRORS {
+            let position =
+                simulate(integrator, Vec3::ZERO, velocity, |_, _| gravity);
+            let error = position.distance(expected);
+            assert!(
+                error < max_error,
+                "{integrator:?} drifted by {error}, more than {max_error}"
+            );
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+/// Bevy [`Component`] smoothing the rendered [`Transform`] of an [`Entity`] by
+/// interpolating between its last two fixed steps.
+///
+/// The dynamics write the [`Transform`] directly on the fixed schedule, which
+/// stutters whenever the render rate differs from the fixed rate. With this
+/// component the translation and rotation are blended in [`PostUpdate`]
+/// between the previous and current step using
+/// [`Time<Fixed>::overstep_fraction`], so the rendered [`Transform`] lags one
+/// step behind the simulation but moves smoothly.
+///
+/// The simulated [`Transform`] is restored before every step, unless it was
+/// changed by another system since it was rendered, in which case the
+/// [`Entity`] is teleported without blending.
+///
+/// Blending only happens when the [`crate::DynamicsPlugin`] uses the
+/// [`Time<Fixed>`] time source and the dynamics advance, otherwise the
+/// simulated [`Transform`] is rendered as is.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[require(Transform)]
+pub struct TransformInterpolation {
+    /// The [`Transform`] at the end of the previous step.
+    pub(crate) previous: Option<Transform>,
+    /// The [`Transform`] at the end of the current step.
+    pub(crate) current: Option<Transform>,
+    /// The [`Transform`] that was last written, either by the dynamics or by
+    /// the blending.
+    pub(crate) rendered: Option<Transform>,
+}
+
+/// Bevy [`Component`] smoothing the rendered [`Transform`] of an [`Entity`] by
+/// extrapolating from its last fixed step.
+///
+/// Like [`TransformInterpolation`], but instead of lagging one step behind the
+/// rendered [`Transform`] is predicted ahead of the current step using the
+/// [`crate::Velocity`] and [`crate::AngularVelocity`] of the [`Entity`]. This
+/// has no latency, but may briefly overshoot when the motion changes, such as
+/// on impact.
+///
+/// If both components are present, [`TransformInterpolation`] is used. Like
+/// interpolation, extrapolation only happens with the [`Time<Fixed>`] time
+/// source.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[require(Transform)]
+pub struct TransformExtrapolation {
+    /// The [`Transform`] at the end of the current step.
+    pub(crate) current: Option<Transform>,
+    /// The [`Transform`] that was last written, either by the dynamics or by
+    /// the blending.
+    pub(crate) rendered: Option<Transform>,
+}
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// Bevy [`Component`] caching the inverse of an [`Entity`]'s [`crate::Mass`].
+///
+/// A value of `0.0` means the [`Entity`] is immovable. The value is kept in
+/// sync with the [`crate::Mass`] when it is inserted or changed, and should
+/// not be modified directly.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct InverseMass(pub f32);
+
+impl Default for InverseMass {
+    /// Create a new [`InverseMass`] component with the default value of `1.0`.
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use super::Sensor;
+
+/// Bevy [`Component`] scaling the passage of time for a single [`Entity`].
+///
+/// A scale of `0.5` runs the [`Entity`] in slow motion at half speed, and a
+/// scale of `0.0` freezes it. The scale is combined with every
+/// [`TimeDilationVolume`] the [`Entity`] is inside. Entities without this
+/// component use a scale of `1.0`.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct LocalTimeScale(pub f32);
+
+impl Default for LocalTimeScale {
+    /// Create a new [`LocalTimeScale`] component with the default value of
+    /// `1.0`.
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Bevy [`Component`] slowing down or speeding up time for every [`Entity`]
+/// inside the region of its [`crate::Collider`], for bullet-time effects.
+///
+/// The time scale of an [`Entity`] is multiplied by the `scale` of every
+/// volume containing its translation, along with its own [`LocalTimeScale`].
+/// Volumes are [`Sensor`]s, so entities entering and leaving them are also
+/// reported with collision events. Volumes without a [`crate::Velocity`] are
+/// used, and can be moved by changing their [`Transform`] directly.
+///
+/// This component requires the [`Sensor`] component, and is only used along
+/// with a [`crate::Collider`].
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(Sensor)]
+pub struct TimeDilationVolume {
+    /// The factor the passage of time is multiplied by inside the volume.
+    pub scale: f32,
+}
+
+impl TimeDilationVolume {
+    /// Create a new [`TimeDilationVolume`] component with the given scale.
+    pub const fn new(scale: f32) -> Self {
+        Self { scale }
+    }
+}
@@ -1,13 +1,22 @@
-use bevy::prelude::*;
+use bevy::{
+    ecs::{component::ComponentId, world::DeferredWorld},
+    prelude::*,
+};
 
-use super::Acceleration;
+use super::{Acceleration, InverseMass};
 
 /// Bevy [`Component`] representing an [`Entity`]'s mass.
-/// This component requires the [`Acceleration`] component, thus also requiring
-/// the [`crate::components::Velocity`], [`crate::components::Damping`] and
-/// [`Transform`] components.
+///
+/// This component requires the [`Acceleration`] and [`InverseMass`]
+/// components, thus also requiring the [`crate::components::Velocity`],
+/// [`crate::components::Damping`] and [`Transform`] components.
+///
+/// The mass must be positive, and an infinite mass makes the [`Entity`]
+/// immovable. Invalid masses are replaced with the default of `1.0` with a
+/// logged error when the component is inserted.
 #[derive(Component)]
-#[require(Acceleration)]
+#[require(Acceleration, InverseMass)]
+#[component(on_insert = validate_mass)]
 pub struct Mass {
     /// Mass in kilograms
     pub value: f32,
@@ -25,4 +34,39 @@ impl Mass {
     pub fn new(value: f32) -> Self {
         Self { value }
     }
+
+    /// Whether the mass is positive, which includes an infinite mass.
+    pub fn is_valid(&self) -> bool {
+        self.value > 0.0
+    }
+
+    /// Get the inverse of the mass, which is `0.0` for an infinite mass.
+    pub fn inverse(&self) -> f32 {
+        self.value.recip()
+    }
+
+    /// Replace an invalid mass with the default, logging an error for the
+    /// given [`Entity`].
+    pub(crate) fn validate(&mut self, entity: Entity) {
+        if !self.is_valid() {
+            error!(
+                "Invalid mass {} on {entity}, using the default mass instead",
+                self.value
+            );
+            *self = Self::default();
+        }
+    }
+}
+
+/// Validate a [`Mass`] when it is inserted, and update its [`InverseMass`].
+fn validate_mass(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let Some(mut mass) = world.get_mut::<Mass>(entity) else {
+        return;
+    };
+    mass.validate(entity);
+    let inverse = mass.inverse();
+
+    if let Some(mut inverse_mass) = world.get_mut::<InverseMass>(entity) {
+        inverse_mass.0 = inverse;
+    }
 }
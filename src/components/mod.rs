@@ -1,20 +1,86 @@
 //! This module contains the components that are used by the [`DynamicsPlugin`].
 //!
-//! Components such as [`Velocity`], [`Acceleration`], [`Mass`] and [`Damping`],
-//! but also optional components such as [`debug::Debug`],
+//! Components such as:
+//! - [`Velocity`], [`Acceleration`], [`Mass`] with its cached [`InverseMass`],
+//!   [`Damping`] and [`Drag`], along with the [`RigidBodyMode`] selecting how
+//!   they are simulated.
+//! - Their angular counterparts [`AngularVelocity`], [`AngularAcceleration`],
+//!   [`Inertia`] and [`AngularDamping`].
+//! - The per-step [`ExternalForce`], [`ExternalImpulse`] and [`ExternalTorque`]
+//!   accumulators, and the [`ForceField`] depending on the position and
+//!   velocity.
+//! - The [`GravityScale`] of the global gravity, and the [`Integrator`] used to
+//!   override the integration scheme per entity.
+//! - The [`LocalTimeScale`] of an entity, and the [`TimeDilationVolume`]
+//!   slowing down every entity inside it.
+//! - The [`Collider`] shape with its [`Restitution`] and [`Friction`] or
+//!   [`Sensor`] marker, and the [`Ccd`] marker for fast moving entities.
+//! - The [`Spring`] joint linking two [`SpringAnchor`]s, and the XPBD
+//!   [`Constraint`] of a [`ConstraintKind`].
+//! - The [`WorldBounds`] with their [`BoundsBehavior`].
+//! - The [`TransformInterpolation`] and [`TransformExtrapolation`] smoothing
+//!   the rendered [`Transform`].
+//!
+//! But also optional components such as [`debug::Debug`],
 //! [`debug::DebugColors`] and [`debug::DebugScale`] hidden behind the `debug`
 //! feature flag.
 
 mod acceleration;
+mod angular_acceleration;
+mod angular_damping;
+mod angular_velocity;
+mod ccd;
+mod collider;
+mod constraint;
 mod damping;
 #[cfg(feature = "debug")]
 mod debug;
+mod drag;
+mod external_force;
+mod external_impulse;
+mod external_torque;
+mod force_field;
+mod friction;
+mod gravity_scale;
+mod inertia;
+mod integrator;
+mod interpolation;
+mod inverse_mass;
+mod local_time_scale;
 mod mass;
+mod restitution;
+mod rigid_body_mode;
+mod sensor;
+mod spring;
 mod velocity;
+mod world_bounds;
 
 pub use acceleration::*;
+pub use angular_acceleration::*;
+pub use angular_damping::*;
+pub use angular_velocity::*;
+pub use ccd::*;
+pub use collider::*;
+pub use constraint::*;
 pub use damping::*;
 #[cfg(feature = "debug")]
 pub use debug::*;
+pub use drag::*;
+pub use external_force::*;
+pub use external_impulse::*;
+pub use external_torque::*;
+pub use force_field::*;
+pub use friction::*;
+pub use gravity_scale::*;
+pub use inertia::*;
+pub use integrator::*;
+pub use interpolation::*;
+pub use inverse_mass::*;
+pub use local_time_scale::*;
 pub use mass::*;
+pub use restitution::*;
+pub use rigid_body_mode::*;
+pub use sensor::*;
+pub use spring::*;
 pub use velocity::*;
+pub use world_bounds::*;
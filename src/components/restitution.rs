@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+/// Bevy [`Component`] determining how bouncy the contacts of a
+/// [`crate::Collider`] are.
+///
+/// A value of `0.0` is perfectly inelastic and a value of `1.0` is perfectly
+/// elastic. The restitution of a contact is the average of both colliders.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Restitution {
+    /// Coefficient of restitution
+    pub value: f32,
+}
+
+impl Default for Restitution {
+    /// Create a new [`Restitution`] component with the default value of
+    /// `0.0`.
+    fn default() -> Self {
+        Self { value: 0.0 }
+    }
+}
+
+impl Restitution {
+    /// Create a new [`Restitution`] component with an f32 value.
+    pub const fn new(value: f32) -> Self {
+        Self { value }
+    }
+}
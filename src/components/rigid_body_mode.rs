@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use super::InverseMass;
+
+/// Bevy [`Component`] selecting how an [`Entity`] with a [`crate::Velocity`]
+/// is simulated.
+///
+/// Entities without this component are [`RigidBodyMode::Dynamic`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RigidBodyMode {
+    /// Moved by forces, gravity, damping, springs, constraints and contacts.
+    #[default]
+    Dynamic,
+    /// Moved only by its [`crate::Velocity`] and [`crate::AngularVelocity`],
+    /// ignoring forces, gravity and damping. Kinematic bodies push dynamic
+    /// bodies in contacts, springs and constraints as if they had an infinite
+    /// mass, such as moving platforms or animated characters.
+    Kinematic,
+    /// Never integrated, but still collides, and anchors springs and
+    /// constraints with an infinite mass.
+    Static,
+}
+
+impl RigidBodyMode {
+    /// Whether the [`Entity`] is moved by forces and contacts.
+    pub fn is_dynamic(self) -> bool {
+        self == Self::Dynamic
+    }
+
+    /// Get the [`InverseMass`], which is `0.0` unless the mode is
+    /// [`RigidBodyMode::Dynamic`]. Dynamic bodies without an [`InverseMass`]
+    /// have a mass of `1.0`.
+    pub fn inverse_mass(self, inverse_mass: Option<&InverseMass>) -> f32 {
+        if self.is_dynamic() {
+            inverse_mass.map_or(1.0, |inverse_mass| inverse_mass.0)
+        } else {
+            0.0
+        }
+    }
+}
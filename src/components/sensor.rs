@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// Bevy [`Component`] marking a [`crate::Collider`] as a sensor or trigger
+/// volume.
+///
+/// Sensors detect overlapping entities with a [`crate::Velocity`] and report
+/// them with [`crate::CollisionStarted`], [`crate::CollisionPersisting`] and
+/// [`crate::CollisionEnded`] events, but never push them apart or apply
+/// contact impulses. Useful for pickup zones, checkpoints and force-field
+/// regions.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sensor;
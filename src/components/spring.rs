@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+/// One end of a [`Spring`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpringAnchor {
+    /// Attached to the translation of an [`Entity`].
+    Entity(Entity),
+    /// Attached to a fixed point in world space.
+    Point(Vec3),
+}
+
+/// Bevy [`Component`] linking two ends with a Hookean spring and damper.
+///
+/// The spring is spawned as its own [`Entity`], so any number of springs can
+/// be attached to the same [`Entity`], such as the planks of a rope bridge.
+/// Every step the spring pulls its ends towards the `rest_length` with a force
+/// of `stiffness × stretch`, and damps the relative velocity along the spring
+/// with a force of `damping × speed`.
+///
+/// The forces are evaluated by the [`crate::Integrator`] of each [`Entity`]
+/// end at every intermediate state of a substep, holding the other end at its
+/// state at the start of the substep, and are converted to acceleration using
+/// the [`crate::InverseMass`]. Ends without a [`crate::Velocity`] do not move.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Spring {
+    /// The first end of the spring.
+    pub first: SpringAnchor,
+    /// The second end of the spring.
+    pub second: SpringAnchor,
+    /// The length at which the spring applies no force.
+    pub rest_length: f32,
+    /// The force per unit of stretch, in newtons per meter.
+    pub stiffness: f32,
+    /// The force per unit of relative speed along the spring, in newton
+    /// seconds per meter.
+    pub damping: f32,
+}
+
+impl Spring {
+    /// Create a new [`Spring`] between two entities with the given rest
+    /// length and stiffness, and no damping.
+    pub const fn new(
+        first: Entity,
+        second: Entity,
+        rest_length: f32,
+        stiffness: f32,
+    ) -> Self {
+        Self {
+            first: SpringAnchor::Entity(first),
+            second: SpringAnchor::Entity(second),
+            rest_length,
+            stiffness,
+            damping: 0.0,
+        }
+    }
+
+    /// Create a new [`Spring`] between an [`Entity`] and a fixed point in
+    /// world space with the given rest length and stiffness, and no damping.
+    pub const fn anchored(
+        entity: Entity,
+        anchor: Vec3,
+        rest_length: f32,
+        stiffness: f32,
+    ) -> Self {
+        Self {
+            first: SpringAnchor::Entity(entity),
+            second: SpringAnchor::Point(anchor),
+            rest_length,
+            stiffness,
+            damping: 0.0,
+        }
+    }
+
+    /// Set the damping of the [`Spring`].
+    #[must_use]
+    pub const fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Get the force applied to the first end, given the position and velocity
+    /// of both ends. The force applied to the second end is its opposite.
+    pub fn force(
+        &self,
+        first_position: Vec3,
+        first_velocity: Vec3,
+        second_position: Vec3,
+        second_velocity: Vec3,
+    ) -> Vec3 {
+        let offset = second_position - first_position;
+        let Some(direction) = offset.try_normalize() else {
+            return Vec3::ZERO;
+        };
+
+        let stretch = offset.length() - self.rest_length;
+        let speed = (second_velocity - first_velocity).dot(direction);
+        direction * self.stiffness.mul_add(stretch, self.damping * speed)
+    }
+}
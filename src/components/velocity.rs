@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::{Acceleration, Damping, Mass};
+use super::{Acceleration, Damping, InverseMass};
 
 /// The speed of light in meters per second.
 const SPEED_OF_LIGHT: f32 = 299_792_458.0;
@@ -28,33 +28,46 @@ impl Velocity {
         acceleration: &Acceleration,
         delta_time_secs: f32,
     ) {
-        let vel = self.value + acceleration.value * delta_time_secs;
-        if vel.length() > SPEED_OF_LIGHT {
-            self.value = vel.normalize() * SPEED_OF_LIGHT;
-        } else {
-            self.value = vel;
+        self.value += acceleration.value * delta_time_secs;
+        self.clamp_to_speed_of_light();
+    }
+
+    /// Clamp the velocity to the speed of light [`SPEED_OF_LIGHT`].
+    pub fn clamp_to_speed_of_light(&mut self) {
+        if self.value.length() > SPEED_OF_LIGHT {
+            self.value = self.value.normalize() * SPEED_OF_LIGHT;
         }
     }
 
-    /// Apply an impulse to the velocity.
-    pub fn apply_impulse(
+    /// Apply an instantaneous impulse to the velocity.
+    ///
+    /// The `impulse` is given in newton-seconds, and changes the velocity by
+    /// `impulse × inverse_mass` meters per second. An [`InverseMass`] of `0.0`
+    /// leaves the velocity unchanged.
+    pub fn apply_impulse(&mut self, impulse: Vec3, inverse_mass: &InverseMass) {
+        self.value += impulse * inverse_mass.0;
+    }
+
+    /// Apply a force for a duration to the velocity.
+    ///
+    /// The `force` is given in newtons and acts for `delta_time_secs` seconds,
+    /// changing the velocity by `force × inverse_mass × delta_time_secs`
+    /// meters per second.
+    pub fn apply_force_for(
         &mut self,
-        impulse: Vec3,
-        mass: &Mass,
+        force: Vec3,
+        inverse_mass: &InverseMass,
         delta_time_secs: f32,
     ) {
-        self.value += impulse / mass.value * delta_time_secs;
+        self.value += force * inverse_mass.0 * delta_time_secs;
     }
 
     /// Apply damping to the velocity to mimic friction.
+    ///
+    /// The speed is reduced according to the [`crate::DampingModel`] of the
+    /// [`Damping`].
     pub fn apply_damping(&mut self, damping: &Damping, delta_time_secs: f32) {
-        self.value *= 1.0 - damping.value * delta_time_secs;
-
-        // like air resistance damping should be proportional to the square of
-        // the speed
-        // self.value *=
-        //     1.0 - damping.value * self.value.length_squared() *
-        // delta_time_secs;
+        self.value *= damping.factor(self.speed(), delta_time_secs);
     }
 
     /// Get the current speed.
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+/// What happens to an [`Entity`] leaving its [`WorldBounds`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub enum BoundsBehavior {
+    /// Bounce off the bounds, scaling the reflected velocity by the
+    /// restitution.
+    Reflect {
+        /// How much of the speed along the crossed axis is kept, `1.0` being
+        /// perfectly elastic.
+        restitution: f32,
+    },
+    /// Wrap around to the opposite side, making the world toroidal.
+    Wrap,
+    /// Clamp the position to the bounds and zero the velocity along the
+    /// crossed axis.
+    Clamp,
+    /// Despawn the [`Entity`].
+    Despawn,
+    /// Leave the [`Entity`] alone, only sending the
+    /// [`crate::OutOfBounds`] event.
+    #[default]
+    Notify,
+}
+
+/// Axis aligned box bounding the world of the dynamics.
+///
+/// Inserted as a [`Resource`] to bound every [`Entity`] with a
+/// [`crate::Velocity`], and can be added as a [`Component`] to give a single
+/// [`Entity`] its own bounds. The [`BoundsBehavior`] of the bounds is used for
+/// every [`Entity`] without its own [`BoundsBehavior`] component.
+///
+/// Entities with a [`crate::Collider`] are kept inside by their bounding
+/// radius, while other entities are treated as points.
+#[derive(Resource, Component, Clone, Copy, Debug, PartialEq)]
+pub struct WorldBounds {
+    /// The minimum corner of the bounds.
+    pub min: Vec3,
+    /// The maximum corner of the bounds.
+    pub max: Vec3,
+    /// The default behavior of entities leaving the bounds.
+    pub behavior: BoundsBehavior,
+}
+
+impl WorldBounds {
+    /// Create new [`WorldBounds`] between `min` and `max`, with the default
+    /// [`BoundsBehavior::Notify`] behavior.
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Self {
+            min,
+            max,
+            behavior: BoundsBehavior::default(),
+        }
+    }
+
+    /// Create new [`WorldBounds`] centered on `center` with the given half
+    /// extents.
+    pub fn from_center_half_extents(center: Vec3, half_extents: Vec3) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Set the default [`BoundsBehavior`] of entities leaving the bounds.
+    #[must_use]
+    pub const fn with_behavior(mut self, behavior: BoundsBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
+    /// Check if a sphere at `position` with the given `radius` is fully
+    /// inside the bounds.
+    pub fn contains(&self, position: Vec3, radius: f32) -> bool {
+        (position - radius).cmpge(self.min).all()
+            && (position + radius).cmple(self.max).all()
+    }
+}
@@ -0,0 +1,701 @@
+//! The `contact` module contains the narrow phase [`contact`] test between two
+//! [`Collider`]s, producing a [`Contact`].
+
+use bevy::prelude::*;
+
+use crate::Collider;
+
+/// Number of iterations used to find the point on a capsule's segment closest
+/// to a box.
+const SEGMENT_SEARCH_ITERATIONS: usize = 24;
+
+/// A contact between two [`Collider`]s `a` and `b`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact {
+    /// The contact point in world space, halfway between the two surfaces.
+    pub point: Vec3,
+    /// The contact normal in world space, pointing from `a` towards `b`.
+    pub normal: Vec3,
+    /// How far the two colliders overlap along the normal.
+    pub depth: f32,
+}
+
+impl Contact {
+    /// Get the same contact as seen from the other collider.
+    #[must_use]
+    pub fn flipped(self) -> Self {
+        Self {
+            normal: -self.normal,
+            ..self
+        }
+    }
+}
+
+/// Test two [`Collider`]s for overlap, returning the [`Contact`] if they
+/// touch.
+pub fn contact(
+    a: &Collider,
+    transform_a: &Transform,
+    b: &Collider,
+    transform_b: &Transform,
+) -> Option<Contact> {
+    match (a, b) {
+        (Collider::HalfSpace { .. }, Collider::HalfSpace { .. }) => None,
+        (Collider::HalfSpace { normal }, _) => {
+            half_space_contact(transform_a, *normal, b, transform_b)
+        }
+        (_, Collider::HalfSpace { normal }) => {
+            half_space_contact(transform_b, *normal, a, transform_a)
+                .map(Contact::flipped)
+        }
+        (
+            Collider::Cuboid { half_extents: ha },
+            Collider::Cuboid { half_extents: hb },
+        ) => cuboid_cuboid_contact(*ha, transform_a, *hb, transform_b),
+        (Collider::Cuboid { half_extents }, _) => {
+            let segment = b.rounded_segment(transform_b)?;
+            rounded_cuboid_contact(segment, *half_extents, transform_a)
+                .map(Contact::flipped)
+        }
+        (_, Collider::Cuboid { half_extents }) => {
+            let segment = a.rounded_segment(transform_a)?;
+            rounded_cuboid_contact(segment, *half_extents, transform_b)
+        }
+        _ => rounded_rounded_contact(
+            a.rounded_segment(transform_a)?,
+            b.rounded_segment(transform_b)?,
+        ),
+    }
+}
+
+/// Get the world space corners of a box.
+pub fn cuboid_corners(half_extents: Vec3, transform: &Transform) -> [Vec3; 8] {
+    std::array::from_fn(|i| {
+        let sign = Vec3::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            if i & 4 == 0 { -1.0 } else { 1.0 },
+        );
+        transform.translation + transform.rotation * (sign * half_extents)
+    })
+}
+
+/// Get the points on two segments `(start_a, end_a)` and `(start_b, end_b)`
+/// closest to each other.
+pub fn closest_points_segment_segment(
+    (start_a, end_a): (Vec3, Vec3),
+    (start_b, end_b): (Vec3, Vec3),
+) -> (Vec3, Vec3) {
+    let direction_a = end_a - start_a;
+    let direction_b = end_b - start_b;
+    let offset = start_a - start_b;
+    let length_squared_a = direction_a.length_squared();
+    let length_squared_b = direction_b.length_squared();
+    let offset_b = direction_b.dot(offset);
+
+    let (t_a, t_b) = if length_squared_a <= f32::EPSILON
+        && length_squared_b <= f32::EPSILON
+    {
+        // Both segments are points
+        (0.0, 0.0)
+    } else if length_squared_a <= f32::EPSILON {
+        // The first segment is a point
+        (0.0, (offset_b / length_squared_b).clamp(0.0, 1.0))
+    } else {
+        let offset_a = direction_a.dot(offset);
+        if length_squared_b <= f32::EPSILON {
+            // The second segment is a point
+            ((-offset_a / length_squared_a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let alignment = direction_a.dot(direction_b);
+            let denominator =
+                length_squared_a.mul_add(length_squared_b, -alignment.powi(2));
+
+            // Parallel segments can use any point on the first segment
+            let t_a = if denominator > f32::EPSILON {
+                (alignment.mul_add(offset_b, -offset_a * length_squared_b)
+                    / denominator)
+                    .clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            let t_b = alignment.mul_add(t_a, offset_b) / length_squared_b;
+            if t_b < 0.0 {
+                ((-offset_a / length_squared_a).clamp(0.0, 1.0), 0.0)
+            } else if t_b > 1.0 {
+                (
+                    ((alignment - offset_a) / length_squared_a).clamp(0.0, 1.0),
+                    1.0,
+                )
+            } else {
+                (t_a, t_b)
+            }
+        }
+    };
+
+    (start_a + direction_a * t_a, start_b + direction_b * t_b)
+}
+
+/// Get the point on a box closest to `point`, both in the local space of the
+/// box.
+pub fn closest_point_cuboid(half_extents: Vec3, point: Vec3) -> Vec3 {
+    point.clamp(-half_extents, half_extents)
+}
+
+/// Contact between a half-space and any other non half-space collider.
+fn half_space_contact(
+    plane_transform: &Transform,
+    local_normal: Dir3,
+    other: &Collider,
+    other_transform: &Transform,
+) -> Option<Contact> {
+    let normal = plane_transform.rotation * *local_normal;
+    let origin = plane_transform.translation;
+
+    // Collect the points of the other collider that reach furthest into the
+    // half-space, along with the radius around them
+    let (points, radius) = if let Collider::Cuboid { half_extents } = other {
+        (cuboid_corners(*half_extents, other_transform).to_vec(), 0.0)
+    } else {
+        let (start, end, radius) = other.rounded_segment(other_transform)?;
+        (vec![start, end], radius)
+    };
+
+    let mut depth = 0.0_f32;
+    let mut point_sum = Vec3::ZERO;
+    let mut count = 0.0;
+    for point in points {
+        let distance = (point - origin).dot(normal) - radius;
+        if distance < 0.0 {
+            depth = depth.max(-distance);
+            point_sum += point - normal * distance.mul_add(0.5, radius);
+            count += 1.0;
+        }
+    }
+
+    (count > 0.0).then(|| Contact {
+        point: point_sum / count,
+        normal,
+        depth,
+    })
+}
+
+/// Contact between two spheres or capsules, given as rounded segments.
+fn rounded_rounded_contact(
+    (start_a, end_a, radius_a): (Vec3, Vec3, f32),
+    (start_b, end_b, radius_b): (Vec3, Vec3, f32),
+) -> Option<Contact> {
+    let (closest_a, closest_b) =
+        closest_points_segment_segment((start_a, end_a), (start_b, end_b));
+
+    let offset = closest_b - closest_a;
+    let distance = offset.length();
+    let depth = radius_a + radius_b - distance;
+    if depth <= 0.0 {
+        return None;
+    }
+
+    let normal = offset.try_normalize().unwrap_or(Vec3::Y);
+    let surface_a = closest_a + normal * radius_a;
+    let surface_b = closest_b - normal * radius_b;
+
+    Some(Contact {
+        point: (surface_a + surface_b) * 0.5,
+        normal,
+        depth,
+    })
+}
+
+/// Contact between a sphere or capsule, given as a rounded segment, and a box.
+fn rounded_cuboid_contact(
+    (start, end, radius): (Vec3, Vec3, f32),
+    half_extents: Vec3,
+    cuboid_transform: &Transform,
+) -> Option<Contact> {
+    let inverse_rotation = cuboid_transform.rotation.inverse();
+    let to_local =
+        |point: Vec3| inverse_rotation * (point - cuboid_transform.translation);
+    let (start, end) = (to_local(start), to_local(end));
+
+    // The distance from the segment to the box is convex along the segment,
+    // so a ternary search finds the closest point
+    let distance_at = |t: f32| {
+        let point = start.lerp(end, t);
+        point.distance(closest_point_cuboid(half_extents, point))
+    };
+    let (mut low, mut high) = (0.0_f32, 1.0_f32);
+    for _ in 0..SEGMENT_SEARCH_ITERATIONS {
+        let left = low + (high - low) / 3.0;
+        let right = high - (high - low) / 3.0;
+        if distance_at(left) <= distance_at(right) {
+            high = right;
+        } else {
+            low = left;
+        }
+    }
+    let point = start.lerp(end, 0.5 * (low + high));
+    let closest = closest_point_cuboid(half_extents, point);
+    let distance = point.distance(closest);
+
+    let (local_normal, depth, local_point) = if distance > f32::EPSILON {
+        // The segment is outside the box, push it out along the offset
+        let normal = (point - closest) / distance;
+        (normal, radius - distance, closest)
+    } else {
+        // The segment is inside the box, push it out through the nearest face
+        let penetration = half_extents - point.abs();
+        let axis = if penetration.x <= penetration.y
+            && penetration.x <= penetration.z
+        {
+            Vec3::X
+        } else if penetration.y <= penetration.z {
+            Vec3::Y
+        } else {
+            Vec3::Z
+        };
+        let sign = point.dot(axis).signum();
+        (axis * sign, radius + penetration.dot(axis), point)
+    };
+
+    if depth <= 0.0 {
+        return None;
+    }
+
+    // The normal points from the rounded collider towards the box
+    let normal = -(cuboid_transform.rotation * local_normal);
+    let point = cuboid_transform.translation
+        + cuboid_transform.rotation * local_point
+        + normal * 0.5 * depth;
+
+    Some(Contact {
+        point,
+        normal,
+        depth,
+    })
+}
+
+/// Contact between two boxes using the separating axis theorem.
+fn cuboid_cuboid_contact(
+    half_extents_a: Vec3,
+    transform_a: &Transform,
+    half_extents_b: Vec3,
+    transform_b: &Transform,
+) -> Option<Contact> {
+    let axes_a = Mat3::from_quat(transform_a.rotation);
+    let axes_b = Mat3::from_quat(transform_b.rotation);
+    let offset = transform_b.translation - transform_a.translation;
+
+    let projected_radius = |half_extents: Vec3, axes: &Mat3, axis: Vec3| {
+        half_extents.dot((axes.transpose() * axis).abs())
+    };
+
+    let face_axes = [
+        axes_a.x_axis,
+        axes_a.y_axis,
+        axes_a.z_axis,
+        axes_b.x_axis,
+        axes_b.y_axis,
+        axes_b.z_axis,
+    ];
+    let edge_axes = [axes_a.x_axis, axes_a.y_axis, axes_a.z_axis]
+        .into_iter()
+        .flat_map(|a| {
+            [axes_b.x_axis, axes_b.y_axis, axes_b.z_axis]
+                .into_iter()
+                .filter_map(move |b| a.cross(b).try_normalize())
+        });
+
+    let mut best: Option<(Vec3, f32)> = None;
+    for axis in face_axes.into_iter().chain(edge_axes) {
+        let overlap = projected_radius(half_extents_a, &axes_a, axis)
+            + projected_radius(half_extents_b, &axes_b, axis)
+            - offset.dot(axis).abs();
+        if overlap <= 0.0 {
+            return None;
+        }
+        if best.is_none_or(|(_, depth)| overlap < depth) {
+            let axis = if offset.dot(axis) < 0.0 { -axis } else { axis };
+            best = Some((axis, overlap));
+        }
+    }
+    let (normal, depth) = best?;
+
+    // Average the corners of each box that lie inside the other box
+    let inside = |point: Vec3, half_extents: Vec3, transform: &Transform| {
+        let local =
+            transform.rotation.inverse() * (point - transform.translation);
+        local.abs().cmple(half_extents + Vec3::splat(1e-4)).all()
+    };
+    let corners = cuboid_corners(half_extents_a, transform_a)
+        .into_iter()
+        .filter(|corner| inside(*corner, half_extents_b, transform_b))
+        .chain(
+            cuboid_corners(half_extents_b, transform_b)
+                .into_iter()
+                .filter(|corner| inside(*corner, half_extents_a, transform_a)),
+        )
+        .collect::<Vec<_>>();
+
+    let point = if corners.is_empty() {
+        // Edge to edge contact, use the point between the corners of each box
+        // reaching furthest towards the other
+        let support = |half_extents: Vec3, transform: &Transform, direction| {
+            cuboid_corners(half_extents, transform)
+                .into_iter()
+                .max_by(|a, b| a.dot(direction).total_cmp(&b.dot(direction)))
+                .unwrap_or(transform.translation)
+        };
+        (support(half_extents_a, transform_a, normal)
+            + support(half_extents_b, transform_b, -normal))
+            * 0.5
+    } else {
+        #[allow(clippy::cast_precision_loss)]
+        let count = corners.len() as f32;
+        corners.iter().sum::<Vec3>() / count
+    };
+
+    Some(Contact {
+        point,
+        normal,
+        depth,
+    })
+}
+
+/// Get the signed distance from `point` to the surface of a [`Collider`],
+/// negative inside, along with the outward surface normal closest to the
+/// point.
+pub fn signed_distance(
+    collider: &Collider,
+    transform: &Transform,
+    point: Vec3,
+) -> (f32, Vec3) {
+    match collider {
+        Collider::HalfSpace { normal } => {
+            let normal = transform.rotation * **normal;
+            ((point - transform.translation).dot(normal), normal)
+        }
+        Collider::Cuboid { half_extents } => {
+            let local =
+                transform.rotation.inverse() * (point - transform.translation);
+            let closest = closest_point_cuboid(*half_extents, local);
+            let outside = local - closest;
+
+            if outside.length_squared() > 0.0 {
+                let distance = outside.length();
+                (distance, transform.rotation * (outside / distance))
+            } else {
+                // Inside, the nearest face is the one with the least
+                // penetration
+                let penetration = *half_extents - local.abs();
+                let axis = if penetration.x <= penetration.y
+                    && penetration.x <= penetration.z
+                {
+                    Vec3::X
+                } else if penetration.y <= penetration.z {
+                    Vec3::Y
+                } else {
+                    Vec3::Z
+                };
+                let normal = axis * local.dot(axis).signum();
+                (-penetration.dot(axis), transform.rotation * normal)
+            }
+        }
+        _ => {
+            let Some((start, end, radius)) =
+                collider.rounded_segment(transform)
+            else {
+                return (f32::INFINITY, Vec3::Y);
+            };
+            let (closest, _) =
+                closest_points_segment_segment((start, end), (point, point));
+            let offset = point - closest;
+            let distance = offset.length();
+            (distance - radius, offset.try_normalize().unwrap_or(Vec3::Y))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, SQRT_2};
+
+    use super::*;
+
+    /// Tolerance of the comparisons, rotations are not exact.
+    const EPSILON: f32 = 1e-5;
+
+    /// Get a [`Transform`] at `(x, y, z)`.
+    fn at(x: f32, y: f32, z: f32) -> Transform {
+        Transform::from_xyz(x, y, z)
+    }
+
+    /// Assert a [`Contact`] was found with the given `normal` and `depth`.
+    #[track_caller]
+    fn assert_contact(contact: Option<Contact>, normal: Vec3, depth: f32) {
+        let contact = contact.expect("no contact found");
+        assert!(
+            contact.normal.abs_diff_eq(normal, EPSILON),
+            "normal {} instead of {normal}",
+            contact.normal
+        );
+        assert!(
+            (contact.depth - depth).abs() < EPSILON,
+            "depth {} instead of {depth}",
+            contact.depth
+        );
+    }
+
+    #[test]
+    fn closest_points_between_segments() {
+        // Crossing segments
+        assert_eq!(
+            closest_points_segment_segment(
+                (Vec3::new(-1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0)),
+                (Vec3::new(0.0, -1.0, 1.0), Vec3::new(0.0, 1.0, 1.0)),
+            ),
+            (Vec3::ZERO, Vec3::new(0.0, 0.0, 1.0))
+        );
+
+        // Parallel segments
+        let (a, b) = closest_points_segment_segment(
+            (Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0)),
+            (Vec3::new(1.0, 1.0, 0.0), Vec3::new(3.0, 1.0, 0.0)),
+        );
+        assert_eq!(b - a, Vec3::Y);
+        assert!((1.0..=2.0).contains(&a.x));
+
+        // Closest at the ends of both segments
+        assert_eq!(
+            closest_points_segment_segment(
+                (Vec3::ZERO, Vec3::X),
+                (Vec3::new(2.0, 0.0, 0.0), Vec3::new(3.0, 1.0, 0.0)),
+            ),
+            (Vec3::X, Vec3::new(2.0, 0.0, 0.0))
+        );
+
+        // Points
+        let point = Vec3::new(0.5, 1.0, 0.0);
+        assert_eq!(
+            closest_points_segment_segment(
+                (point, point),
+                (Vec3::ZERO, Vec3::X)
+            ),
+            (point, Vec3::new(0.5, 0.0, 0.0))
+        );
+        assert_eq!(
+            closest_points_segment_segment(
+                (Vec3::ZERO, Vec3::X),
+                (point, point)
+            ),
+            (Vec3::new(0.5, 0.0, 0.0), point)
+        );
+        assert_eq!(
+            closest_points_segment_segment((point, point), (Vec3::X, Vec3::X)),
+            (point, Vec3::X)
+        );
+    }
+
+    #[test]
+    fn closest_point_on_cuboid() {
+        let half_extents = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(
+            closest_point_cuboid(half_extents, Vec3::new(2.0, -1.0, -5.0)),
+            Vec3::new(1.0, -1.0, -3.0)
+        );
+        assert_eq!(
+            closest_point_cuboid(half_extents, Vec3::new(0.5, 1.0, 0.0)),
+            Vec3::new(0.5, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn sphere_sphere() {
+        let sphere = Collider::sphere(1.0);
+
+        let touching =
+            contact(&sphere, &at(0.0, 0.0, 0.0), &sphere, &at(1.5, 0.0, 0.0));
+        assert_contact(touching, Vec3::X, 0.5);
+        assert_eq!(touching.unwrap().point, Vec3::new(0.75, 0.0, 0.0));
+
+        assert_contact(
+            contact(&sphere, &at(1.5, 0.0, 0.0), &sphere, &at(0.0, 0.0, 0.0)),
+            Vec3::NEG_X,
+            0.5,
+        );
+        assert!(contact(
+            &sphere,
+            &at(0.0, 0.0, 0.0),
+            &sphere,
+            &at(2.5, 0.0, 0.0)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn capsule_cuboid() {
+        // A capsule lying along the x axis, just above the box
+        let capsule = Collider::capsule(0.5, 1.0);
+        let lying =
+            |y| at(0.0, y, 0.0).with_rotation(Quat::from_rotation_z(FRAC_PI_2));
+        let cuboid = Collider::cuboid(Vec3::ONE);
+        let origin = at(0.0, 0.0, 0.0);
+
+        assert_contact(
+            contact(&capsule, &lying(1.25), &cuboid, &origin),
+            Vec3::NEG_Y,
+            0.25,
+        );
+        assert_contact(
+            contact(&cuboid, &origin, &capsule, &lying(1.25)),
+            Vec3::Y,
+            0.25,
+        );
+        assert!(contact(&capsule, &lying(1.6), &cuboid, &origin).is_none());
+
+        // A sphere sunk into the side of the box is pushed out of the nearest
+        // face
+        assert_contact(
+            contact(
+                &Collider::sphere(0.5),
+                &at(0.75, 0.0, 0.0),
+                &cuboid,
+                &origin,
+            ),
+            Vec3::NEG_X,
+            0.75,
+        );
+    }
+
+    #[test]
+    fn cuboid_cuboid() {
+        let cuboid = Collider::cuboid(Vec3::ONE);
+        let origin = at(0.0, 0.0, 0.0);
+
+        assert_contact(
+            contact(&cuboid, &origin, &cuboid, &at(1.8, 0.5, 0.0)),
+            Vec3::X,
+            0.2,
+        );
+        assert_contact(
+            contact(&cuboid, &at(1.8, 0.5, 0.0), &cuboid, &origin),
+            Vec3::NEG_X,
+            0.2,
+        );
+        assert!(
+            contact(&cuboid, &origin, &cuboid, &at(2.1, 0.0, 0.0)).is_none()
+        );
+
+        // A box standing on one of its edges next to the other
+        let diamond =
+            |x| at(x, 0.0, 0.0).with_rotation(Quat::from_rotation_z(FRAC_PI_4));
+        assert_contact(
+            contact(&cuboid, &origin, &cuboid, &diamond(2.3)),
+            Vec3::X,
+            1.0 + SQRT_2 - 2.3,
+        );
+        assert!(contact(&cuboid, &origin, &cuboid, &diamond(2.5)).is_none());
+    }
+
+    #[test]
+    fn cuboid_cuboid_edges() {
+        // Two crossed edges, only separated along their cross product
+        let cuboid = Collider::cuboid(Vec3::ONE);
+        let a =
+            at(0.0, 0.0, 0.0).with_rotation(Quat::from_rotation_z(FRAC_PI_4));
+        let b =
+            |y| at(0.0, y, 0.0).with_rotation(Quat::from_rotation_x(FRAC_PI_4));
+
+        assert_contact(
+            contact(&cuboid, &a, &cuboid, &b(2.0 * SQRT_2 - 0.05)),
+            Vec3::Y,
+            0.05,
+        );
+        assert!(
+            contact(&cuboid, &a, &cuboid, &b(2.0 * SQRT_2 + 0.05)).is_none()
+        );
+    }
+
+    #[test]
+    fn half_space() {
+        let ground = Collider::half_space(Dir3::Y);
+        let origin = at(0.0, 0.0, 0.0);
+        let sphere = Collider::sphere(1.0);
+        let cuboid = Collider::cuboid(Vec3::ONE);
+
+        // The normal points out of the half-space when it is `a`
+        let contact_sphere =
+            contact(&ground, &origin, &sphere, &at(0.0, 0.5, 0.0));
+        assert_contact(contact_sphere, Vec3::Y, 0.5);
+        assert_eq!(contact_sphere.unwrap().point, Vec3::new(0.0, -0.25, 0.0));
+        assert_contact(
+            contact(&ground, &origin, &cuboid, &at(0.0, 0.75, 0.0)),
+            Vec3::Y,
+            0.25,
+        );
+
+        // And into it when it is `b`
+        assert_contact(
+            contact(&sphere, &at(0.0, 0.5, 0.0), &ground, &origin),
+            Vec3::NEG_Y,
+            0.5,
+        );
+        assert_contact(
+            contact(&cuboid, &at(0.0, 0.75, 0.0), &ground, &origin),
+            Vec3::NEG_Y,
+            0.25,
+        );
+
+        // The half-space can be rotated and moved
+        let wall =
+            at(2.0, 0.0, 0.0).with_rotation(Quat::from_rotation_z(FRAC_PI_2));
+        assert_contact(
+            contact(&ground, &wall, &sphere, &at(1.5, 0.0, 0.0)),
+            Vec3::NEG_X,
+            0.5,
+        );
+
+        assert!(
+            contact(&ground, &origin, &sphere, &at(0.0, 1.5, 0.0)).is_none()
+        );
+        assert!(
+            contact(&cuboid, &at(0.0, 1.5, 0.0), &ground, &origin).is_none()
+        );
+        assert!(contact(&ground, &origin, &ground, &origin).is_none());
+    }
+
+    #[test]
+    fn signed_distances() {
+        let origin = at(0.0, 0.0, 0.0);
+        let point = Vec3::new(3.0, 0.0, 0.0);
+
+        assert_eq!(
+            signed_distance(&Collider::sphere(1.0), &origin, point),
+            (2.0, Vec3::X)
+        );
+        assert_eq!(
+            signed_distance(
+                &Collider::capsule(0.5, 1.0),
+                &origin,
+                Vec3::new(0.0, 3.0, 0.0)
+            ),
+            (1.5, Vec3::Y)
+        );
+
+        let cuboid = Collider::cuboid(Vec3::ONE);
+        assert_eq!(signed_distance(&cuboid, &origin, point), (2.0, Vec3::X));
+        assert_eq!(
+            signed_distance(&cuboid, &origin, Vec3::new(0.2, -0.5, 0.0)),
+            (-0.5, Vec3::NEG_Y)
+        );
+
+        assert_eq!(
+            signed_distance(
+                &Collider::half_space(Dir3::Y),
+                &at(0.0, 1.0, 0.0),
+                point
+            ),
+            (-1.0, Vec3::Y)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! The `events` module contains the events sent by the [`crate::DynamicsPlugin`].
+//!
+//! Events are both sent as buffered [`Event`]s that can be read with an
+//! [`EventReader`], and triggered on the involved entities so they can be
+//! observed with [`Trigger`].
+
+use bevy::prelude::*;
+
+use crate::Contact;
+
+/// Sent when two [`crate::Collider`]s start touching.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct CollisionStarted {
+    /// The first [`Entity`] of the collision.
+    pub a: Entity,
+    /// The second [`Entity`] of the collision.
+    pub b: Entity,
+    /// The [`Contact`] between the two entities, with the normal pointing
+    /// from `a` towards `b`.
+    pub contact: Contact,
+    /// The magnitude of the normal impulse applied to resolve the contact, in
+    /// newton-seconds.
+    pub impulse: f32,
+}
+
+/// Sent every step two [`crate::Collider`]s keep touching after they started.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct CollisionPersisting {
+    /// The first [`Entity`] of the collision.
+    pub a: Entity,
+    /// The second [`Entity`] of the collision.
+    pub b: Entity,
+    /// The [`Contact`] between the two entities, with the normal pointing
+    /// from `a` towards `b`.
+    pub contact: Contact,
+    /// The magnitude of the normal impulse applied to resolve the contact, in
+    /// newton-seconds.
+    pub impulse: f32,
+}
+
+/// Sent when two [`crate::Collider`]s stop touching.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollisionEnded {
+    /// The first [`Entity`] of the collision.
+    pub a: Entity,
+    /// The second [`Entity`] of the collision.
+    pub b: Entity,
+}
+
+/// Sent when an [`Entity`] leaves its [`crate::WorldBounds`], whatever its
+/// [`crate::BoundsBehavior`] is.
+///
+/// The event is sent once per exit, an [`Entity`] staying outside with
+/// [`crate::BoundsBehavior::Notify`] is not reported again until it comes
+/// back inside.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct OutOfBounds {
+    /// The [`Entity`] that left the bounds.
+    pub entity: Entity,
+    /// The position of the [`Entity`] when it left the bounds.
+    pub position: Vec3,
+    /// The velocity of the [`Entity`] when it left the bounds.
+    pub velocity: Vec3,
+}
+
+/// Sent when the position, rotation or velocity of an [`Entity`] stop being
+/// finite during a substep, before the [`crate::DivergencePolicy`] of the
+/// [`crate::DynamicsConfig`] is applied.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct DynamicsDiverged {
+    /// The [`Entity`] that diverged.
+    pub entity: Entity,
+    /// The position of the [`Entity`] when it diverged.
+    pub position: Vec3,
+    /// The rotation of the [`Entity`] when it diverged.
+    pub rotation: Quat,
+    /// The velocity of the [`Entity`] when it diverged.
+    pub velocity: Vec3,
+    /// The angular velocity of the [`Entity`] when it diverged, which is zero
+    /// without a [`crate::AngularVelocity`].
+    pub angular_velocity: Vec3,
+}
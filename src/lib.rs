@@ -15,7 +15,7 @@ use bevy_dynamics::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, DynamicsPlugin))
+        .add_plugins((DefaultPlugins, DynamicsPlugin::default()))
         .add_systems(Startup, (spawn_particle))
         .run();
 }
@@ -41,9 +41,17 @@ fn spawn_particle(mut commands: Commands) {
 */
 
 mod components;
+mod contact;
+mod events;
 mod plugins;
+mod resources;
+mod sets;
 mod systems;
 
 pub use components::*;
+pub use contact::{signed_distance, Contact};
+pub use events::*;
 pub use plugins::*;
+pub use resources::*;
+pub use sets::*;
 pub use systems::*;
@@ -1,21 +1,169 @@
 //! The `plugins` module contains the [`DynamicsPlugin`].
 
-use bevy::prelude::*;
+use std::{any::TypeId, marker::PhantomData};
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
 
-use crate::apply_dynamics;
 #[cfg(feature = "debug")]
 use crate::debug;
+use crate::{
+    apply_ccd, apply_dynamics, apply_world_bounds, dynamics_running,
+    extrapolate_transforms, interpolate_transforms, record_ccd_start,
+    record_transforms, resolve_contacts, restore_transforms,
+    update_dynamics_time, update_inverse_mass, update_spatial_index,
+    CollisionEnded, CollisionPersisting, CollisionStarted, DivergencePolicy,
+    Dynamics, DynamicsConfig, DynamicsDiverged, DynamicsSet, Gravity,
+    Integrator, OutOfBounds, SimulationControl, SpatialIndex,
+};
 
 /// The Bevy [`Plugin`] for the [`DynamicsPlugin`].
-pub struct DynamicsPlugin;
+///
+/// The dynamics are stepped on the `S` schedule, using the delta of the
+/// `Time<T>` time source. By default the plugin runs on [`FixedUpdate`] with
+/// [`Time<Fixed>`], and [`DynamicsPlugin::new`] runs it on another schedule,
+/// such as [`Update`] with the variable delta of [`Time<Virtual>`], a custom
+/// fixed schedule, or a rollback schedule.
+pub struct DynamicsPlugin<S = FixedUpdate, T = Fixed> {
+    /// The [`DynamicsConfig`] inserted as a resource when the plugin is built.
+    pub config: DynamicsConfig,
+    /// The schedule the dynamics are stepped on.
+    schedule: S,
+    /// The time source whose delta is used for each step.
+    time: PhantomData<fn() -> T>,
+}
+
+impl Default for DynamicsPlugin {
+    /// Create a new [`DynamicsPlugin`] running on [`FixedUpdate`] with
+    /// [`Time<Fixed>`].
+    fn default() -> Self {
+        Self::new(FixedUpdate)
+    }
+}
 
-impl Plugin for DynamicsPlugin {
+impl<S: ScheduleLabel + Clone, T: Default + Send + Sync + 'static>
+    DynamicsPlugin<S, T>
+{
+    /// Create a new [`DynamicsPlugin`] stepping the dynamics on the given
+    /// schedule, using the delta of the `Time<T>` time source.
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use bevy_dynamics::DynamicsPlugin;
+    ///
+    /// App::new().add_plugins(DynamicsPlugin::<_, Virtual>::new(Update));
+    /// ```
+    pub const fn new(schedule: S) -> Self {
+        Self {
+            config: DynamicsConfig::DEFAULT,
+            schedule,
+            time: PhantomData,
+        }
+    }
+
+    /// Set the [`DynamicsConfig`] used by the plugin.
+    #[must_use]
+    pub const fn with_config(mut self, config: DynamicsConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Set the default [`Integrator`] used by the plugin.
+    #[must_use]
+    pub const fn with_integrator(mut self, integrator: Integrator) -> Self {
+        self.config = self.config.with_integrator(integrator);
+        self
+    }
+
+    /// Set the number of substeps each step is divided into.
+    #[must_use]
+    pub const fn with_substeps(mut self, substeps: u32) -> Self {
+        self.config = self.config.with_substeps(substeps);
+        self
+    }
+
+    /// Set the number of constraint iterations per substep.
+    #[must_use]
+    pub const fn with_constraint_iterations(mut self, iterations: u32) -> Self {
+        self.config = self.config.with_constraint_iterations(iterations);
+        self
+    }
+
+    /// Set the [`DivergencePolicy`] used by the plugin.
+    #[must_use]
+    pub const fn with_divergence_policy(
+        mut self,
+        policy: DivergencePolicy,
+    ) -> Self {
+        self.config = self.config.with_divergence_policy(policy);
+        self
+    }
+}
+
+impl<S: ScheduleLabel + Clone, T: Default + Send + Sync + 'static> Plugin
+    for DynamicsPlugin<S, T>
+{
     fn build(&self, app: &mut App) {
         #[cfg(feature = "debug")]
         {
-            app.add_systems(Update, debug);
+            app.add_systems(Update, debug.in_set(DynamicsSet::Debug));
         }
 
-        app.add_systems(FixedUpdate, apply_dynamics);
+        app.insert_resource(self.config);
+        app.init_resource::<Time<Dynamics>>();
+        app.init_resource::<SimulationControl>();
+        app.init_resource::<Gravity>();
+        app.init_resource::<SpatialIndex>();
+        app.add_event::<CollisionStarted>();
+        app.add_event::<CollisionPersisting>();
+        app.add_event::<CollisionEnded>();
+        app.add_event::<OutOfBounds>();
+        app.add_event::<DynamicsDiverged>();
+        app.configure_sets(
+            self.schedule.clone(),
+            DynamicsSet::AdvanceTime.before(DynamicsSet::PrepareForces),
+        );
+        app.configure_sets(
+            self.schedule.clone(),
+            (
+                DynamicsSet::PrepareForces,
+                DynamicsSet::ApplyForces,
+                DynamicsSet::Integrate,
+                DynamicsSet::ResolveCollisions,
+                DynamicsSet::Writeback,
+            )
+                .chain()
+                .run_if(dynamics_running),
+        );
+        app.add_systems(
+            self.schedule.clone(),
+            (
+                update_dynamics_time::<T>.in_set(DynamicsSet::AdvanceTime),
+                (restore_transforms, (update_inverse_mass, record_ccd_start))
+                    .chain()
+                    .in_set(DynamicsSet::PrepareForces),
+                apply_dynamics.in_set(DynamicsSet::Integrate),
+                (apply_ccd, update_spatial_index, resolve_contacts)
+                    .chain()
+                    .in_set(DynamicsSet::ResolveCollisions),
+                (apply_world_bounds, record_transforms)
+                    .chain()
+                    .in_set(DynamicsSet::Writeback),
+            ),
+        );
+        app.configure_sets(
+            PostUpdate,
+            DynamicsSet::Interpolate
+                .before(TransformSystem::TransformPropagate),
+        );
+
+        // Blending uses the overstep of the fixed schedule, which is
+        // meaningless for any other time source
+        if TypeId::of::<T>() == TypeId::of::<Fixed>() {
+            app.add_systems(
+                PostUpdate,
+                (interpolate_transforms, extrapolate_transforms)
+                    .in_set(DynamicsSet::Interpolate),
+            );
+        }
     }
 }
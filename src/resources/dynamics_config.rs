@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+
+use crate::Integrator;
+
+/// How an [`Entity`] is recovered when its position, rotation or velocity
+/// stop being finite, after a [`crate::DynamicsDiverged`] event is sent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DivergencePolicy {
+    /// Reset the [`Transform`] and velocities to the last step they were
+    /// finite, or replace every non-finite value with zero if they never
+    /// were.
+    #[default]
+    ResetToLastGood,
+    /// Zero the velocities, and reset a non-finite [`Transform`] to the last
+    /// step it was finite.
+    ZeroVelocity,
+    /// Despawn the [`Entity`].
+    Despawn,
+    /// Panic in debug builds, to catch the source of the divergence. Release
+    /// builds fall back to [`DivergencePolicy::ResetToLastGood`].
+    Panic,
+}
+
+/// Bevy [`Resource`] configuring how the dynamics are stepped.
+///
+/// Set when building the [`crate::DynamicsPlugin`], and can be changed at
+/// runtime to take effect from the next step.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynamicsConfig {
+    /// The [`Integrator`] used for every [`Entity`] without its own
+    /// [`Integrator`] component.
+    pub integrator: Integrator,
+    /// The number of integration substeps each step is divided into. More
+    /// substeps keep stiff springs and fast motion stable without changing
+    /// the fixed rate.
+    pub substeps: u32,
+    /// The number of times every [`crate::Constraint`] is solved per substep.
+    /// More iterations make long chains of constraints stiffer.
+    pub constraint_iterations: u32,
+    /// How entities whose state stopped being finite are recovered.
+    pub divergence_policy: DivergencePolicy,
+}
+
+impl Default for DynamicsConfig {
+    /// Create a new [`DynamicsConfig`] resource with the default
+    /// [`Integrator`], `1` substep, `4` constraint iterations and the default
+    /// [`DivergencePolicy`].
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl DynamicsConfig {
+    /// The default [`DynamicsConfig`], usable in constant contexts.
+    pub const DEFAULT: Self = Self {
+        integrator: Integrator::SemiImplicitEuler,
+        substeps: 1,
+        constraint_iterations: 4,
+        divergence_policy: DivergencePolicy::ResetToLastGood,
+    };
+
+    /// Set the default [`Integrator`].
+    #[must_use]
+    pub const fn with_integrator(mut self, integrator: Integrator) -> Self {
+        self.integrator = integrator;
+        self
+    }
+
+    /// Set the number of substeps per step, which is at least `1`.
+    #[must_use]
+    pub const fn with_substeps(mut self, substeps: u32) -> Self {
+        self.substeps = if substeps > 0 { substeps } else { 1 };
+        self
+    }
+
+    /// Set the number of constraint iterations per substep.
+    #[must_use]
+    pub const fn with_constraint_iterations(mut self, iterations: u32) -> Self {
+        self.constraint_iterations = iterations;
+        self
+    }
+
+    /// Set the [`DivergencePolicy`].
+    #[must_use]
+    pub const fn with_divergence_policy(
+        mut self,
+        policy: DivergencePolicy,
+    ) -> Self {
+        self.divergence_policy = policy;
+        self
+    }
+}
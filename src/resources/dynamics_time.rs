@@ -0,0 +1,9 @@
+/// The [`Time`](bevy::time::Time) context of the dynamics, available as the
+/// `Time<Dynamics>` resource.
+///
+/// It is advanced at the start of every step by the delta of the time source
+/// of the [`crate::DynamicsPlugin`], which is `Time<Fixed>` by default, scaled
+/// by the [`crate::SimulationControl`]. Its delta is used by every system of
+/// the plugin.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dynamics;
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+/// Bevy [`Resource`] representing the global gravitational acceleration.
+///
+/// Gravity is applied to every [`Entity`] with a [`crate::Velocity`] in
+/// addition to its own [`crate::Acceleration`], scaled by its
+/// [`crate::GravityScale`]. Defaults to `[0, 0, 0]` so that no gravity is
+/// applied unless it is inserted explicitly.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct Gravity(pub Vec3);
+
+impl Gravity {
+    /// Standard gravity at the surface of the Earth, `9.80665` meters per
+    /// second squared pointing down the y-axis.
+    pub const EARTH: Self = Self(Vec3::new(0.0, -9.80665, 0.0));
+}
+
+impl Default for Gravity {
+    /// Create a new [`Gravity`] resource with the default value of `[0, 0,
+    /// 0]`.
+    fn default() -> Self {
+        Self(Vec3::ZERO)
+    }
+}
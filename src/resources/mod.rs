@@ -0,0 +1,19 @@
+//! The `resources` module contains the resources that are used by the
+//! [`crate::DynamicsPlugin`].
+//!
+//! Resources such as the global [`Gravity`], the [`DynamicsConfig`] used to
+//! step the dynamics, the [`Time`] clock of the [`Dynamics`] context with its
+//! [`SimulationControl`], and the [`SpatialIndex`] broad phase shared by the
+//! built-in systems and your own.
+
+mod dynamics_config;
+mod dynamics_time;
+mod gravity;
+mod simulation_control;
+mod spatial_index;
+
+pub use dynamics_config::*;
+pub use dynamics_time::*;
+pub use gravity::*;
+pub use simulation_control::*;
+pub use spatial_index::*;
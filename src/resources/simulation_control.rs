@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+/// Bevy [`Resource`] controlling how fast the dynamics advance, independently
+/// of the global [`Time<Virtual>`].
+///
+/// The dynamics can be paused, advanced by a number of steps while paused,
+/// and slowed down or sped up with a time scale. The scale changes the delta
+/// of each step rather than the number of steps, so a time scale of `0.5`
+/// plays the simulation in slow motion at the same step rate.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct SimulationControl {
+    /// Whether the dynamics are paused.
+    pub paused: bool,
+    /// The number of steps left to advance while paused.
+    pub pending_steps: u32,
+    /// The factor each step's delta is multiplied by. Negative, NaN or
+    /// infinite scales stop the dynamics.
+    pub time_scale: f32,
+}
+
+impl Default for SimulationControl {
+    /// Create a new running [`SimulationControl`] resource with a time scale
+    /// of `1.0`.
+    fn default() -> Self {
+        Self {
+            paused: false,
+            pending_steps: 0,
+            time_scale: 1.0,
+        }
+    }
+}
+
+impl SimulationControl {
+    /// Pause the dynamics.
+    pub const fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resume the dynamics, dropping any pending steps.
+    pub const fn resume(&mut self) {
+        self.paused = false;
+        self.pending_steps = 0;
+    }
+
+    /// Pause the dynamics if running, or resume them if paused.
+    pub const fn toggle(&mut self) {
+        if self.paused {
+            self.resume();
+        } else {
+            self.pause();
+        }
+    }
+
+    /// Advance the dynamics by `steps` more steps while paused.
+    pub const fn step(&mut self, steps: u32) {
+        self.pending_steps = self.pending_steps.saturating_add(steps);
+    }
+
+    /// Set the time scale, which is clamped to be non-negative.
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.max(0.0);
+    }
+
+    /// Consume a step, returning whether the dynamics should advance during
+    /// it.
+    pub(crate) const fn advance(&mut self) -> bool {
+        if !self.paused {
+            return true;
+        }
+        if self.pending_steps > 0 {
+            self.pending_steps -= 1;
+            return true;
+        }
+        false
+    }
+}
@@ -0,0 +1,447 @@
+use bevy::{math::I64Vec3, prelude::*, utils::HashMap};
+
+/// The maximum number of grid cells an entry is stored in, larger entries are
+/// kept in a separate list scanned linearly.
+const MAX_CELLS_PER_ENTRY: i64 = 64;
+
+/// An [`Entity`] stored in the [`SpatialIndex`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpatialEntry {
+    /// The indexed [`Entity`].
+    pub entity: Entity,
+    /// The translation of the [`Entity`] when the index was last updated.
+    pub position: Vec3,
+    /// The radius of a sphere around the position containing the
+    /// [`Entity`]'s [`crate::Collider`], or `0.0` for a point.
+    pub radius: f32,
+    /// The minimum corner of the axis aligned box containing the [`Entity`]'s
+    /// [`crate::Collider`].
+    pub min: Vec3,
+    /// The maximum corner of the axis aligned box containing the [`Entity`]'s
+    /// [`crate::Collider`].
+    pub max: Vec3,
+}
+
+impl SpatialEntry {
+    /// Whether the bounding spheres and boxes of both entries overlap.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all()
+            && other.min.cmple(self.max).all()
+            && self.position.distance(other.position)
+                <= self.radius + other.radius
+    }
+}
+
+/// Bevy [`Resource`] providing a uniform grid spatial hash broad phase.
+///
+/// The index is rebuilt every step after integration from every [`Entity`]
+/// with a [`crate::Velocity`] or a [`crate::Collider`], and can be queried by
+/// your own systems for neighbours with [`SpatialIndex::query_radius`],
+/// [`SpatialIndex::query_aabb`] and [`SpatialIndex::k_nearest`].
+///
+/// Entities with an infinite bounding radius, such as half-spaces, are not
+/// stored in the grid but are kept in [`SpatialIndex::unbounded`]. Entities
+/// whose bounding box covers too many cells, such as large floors or volumes,
+/// are not stored in the grid either, and are checked against every query.
+#[derive(Resource, Debug)]
+pub struct SpatialIndex {
+    /// The size of a grid cell along each axis.
+    cell_size: f32,
+    /// Every bounded [`Entity`] in the index.
+    entries: Vec<SpatialEntry>,
+    /// Every [`Entity`] with an infinite bounding radius.
+    unbounded: Vec<Entity>,
+    /// The indices into `entries` covering too many cells to be stored in
+    /// the grid.
+    oversized: Vec<usize>,
+    /// The indices into `entries` overlapping each grid cell.
+    cells: HashMap<IVec3, Vec<usize>>,
+    /// The bounds of every entry in the index.
+    bounds: Option<(Vec3, Vec3)>,
+}
+
+impl Default for SpatialIndex {
+    /// Create a new empty [`SpatialIndex`] with a cell size of `1.0`.
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl SpatialIndex {
+    /// Create a new empty [`SpatialIndex`] with the given cell size.
+    ///
+    /// A good cell size is around the diameter of the typical
+    /// [`crate::Collider`] or the typical query radius.
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            entries: Vec::new(),
+            unbounded: Vec::new(),
+            oversized: Vec::new(),
+            cells: HashMap::default(),
+            bounds: None,
+        }
+    }
+
+    /// Get the size of a grid cell along each axis.
+    pub const fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Get every bounded entry in the index.
+    pub fn entries(&self) -> &[SpatialEntry] {
+        &self.entries
+    }
+
+    /// Get every [`Entity`] with an infinite bounding radius.
+    pub fn unbounded(&self) -> &[Entity] {
+        &self.unbounded
+    }
+
+    /// Remove every [`Entity`] from the index.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.unbounded.clear();
+        self.oversized.clear();
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    /// Insert an [`Entity`] at `position` with a bounding `radius`, bounded
+    /// by the box around its bounding sphere.
+    pub fn insert(&mut self, entity: Entity, position: Vec3, radius: f32) {
+        self.insert_aabb(
+            entity,
+            position,
+            radius,
+            position - radius,
+            position + radius,
+        );
+    }
+
+    /// Insert an [`Entity`] at `position` with a bounding `radius`, and an
+    /// axis aligned bounding box from `min` to `max`.
+    pub fn insert_aabb(
+        &mut self,
+        entity: Entity,
+        position: Vec3,
+        radius: f32,
+        min: Vec3,
+        max: Vec3,
+    ) {
+        if !radius.is_finite() {
+            self.unbounded.push(entity);
+            return;
+        }
+
+        let index = self.entries.len();
+        self.entries.push(SpatialEntry {
+            entity,
+            position,
+            radius,
+            min,
+            max,
+        });
+
+        let finite = min.is_finite() && max.is_finite();
+        if finite {
+            self.bounds =
+                Some(self.bounds.map_or((min, max), |(lower, upper)| {
+                    (lower.min(min), upper.max(max))
+                }));
+        }
+
+        // Entries that are not finite or cover too many cells are scanned
+        // linearly instead of being stored in the grid
+        let (min_cell, max_cell) = (self.cell(min), self.cell(max));
+        if !finite || cell_count(min_cell, max_cell) > MAX_CELLS_PER_ENTRY {
+            self.oversized.push(index);
+            return;
+        }
+
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                for z in min_cell.z..=max_cell.z {
+                    self.cells
+                        .entry(IVec3::new(x, y, z))
+                        .or_default()
+                        .push(index);
+                }
+            }
+        }
+    }
+
+    /// Get every [`Entity`] whose bounding sphere intersects the sphere at
+    /// `center` with the given `radius`.
+    pub fn query_radius(&self, center: Vec3, radius: f32) -> Vec<Entity> {
+        self.candidates(center - radius, center + radius)
+            .filter(|entry| {
+                entry.position.distance(center) <= entry.radius + radius
+            })
+            .map(|entry| entry.entity)
+            .collect()
+    }
+
+    /// Get every [`Entity`] whose bounding sphere intersects the axis aligned
+    /// box from `min` to `max`.
+    pub fn query_aabb(&self, min: Vec3, max: Vec3) -> Vec<Entity> {
+        self.candidates(min, max)
+            .filter(|entry| {
+                entry.position.clamp(min, max).distance(entry.position)
+                    <= entry.radius
+            })
+            .map(|entry| entry.entity)
+            .collect()
+    }
+
+    /// Get the `k` entities whose positions are nearest to `point`, sorted
+    /// from nearest to furthest.
+    ///
+    /// Returns no entities if `point` is not finite.
+    pub fn k_nearest(&self, point: Vec3, k: usize) -> Vec<Entity> {
+        let Some((min, max)) = self.bounds else {
+            return Vec::new();
+        };
+        if k == 0 || !point.is_finite() {
+            return Vec::new();
+        }
+
+        // Grow the search radius until it holds k entities, or covers the
+        // whole index
+        let max_radius = point.distance(point.clamp(min, max))
+            + (max - min).length()
+            + self.cell_size;
+        let mut radius = self.cell_size;
+        loop {
+            let mut found = self
+                .candidates(point - radius, point + radius)
+                .map(|entry| (entry.position.distance(point), entry.entity))
+                .filter(|(distance, _)| *distance <= radius)
+                .collect::<Vec<_>>();
+
+            if found.len() >= k || radius >= max_radius {
+                found.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+                return found
+                    .into_iter()
+                    .take(k)
+                    .map(|(_, entity)| entity)
+                    .collect();
+            }
+
+            radius *= 2.0;
+        }
+    }
+
+    /// Get every pair of entries whose bounding spheres and boxes overlap,
+    /// each pair reported once.
+    pub fn overlapping_pairs(&self) -> Vec<(Entity, Entity)> {
+        let mut pairs = Vec::new();
+
+        // Oversized entries are paired with every other entry, and with the
+        // oversized entries after them
+        for (i, &a) in self.oversized.iter().enumerate() {
+            let entry_a = &self.entries[a];
+            for (b, entry_b) in self.entries.iter().enumerate() {
+                if a == b || self.oversized[..=i].contains(&b) {
+                    continue;
+                }
+                if entry_a.overlaps(entry_b) {
+                    pairs.push((entry_a.entity, entry_b.entity));
+                }
+            }
+        }
+
+        for (cell, indices) in &self.cells {
+            for (i, &a) in indices.iter().enumerate() {
+                for &b in &indices[i + 1..] {
+                    let (entry_a, entry_b) =
+                        (&self.entries[a], &self.entries[b]);
+
+                    // Pairs spanning several cells are only reported from the
+                    // lowest cell they share
+                    let shared =
+                        self.cell(entry_a.min).max(self.cell(entry_b.min));
+                    if shared != *cell {
+                        continue;
+                    }
+
+                    if entry_a.overlaps(entry_b) {
+                        pairs.push((entry_a.entity, entry_b.entity));
+                    }
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Get the grid cell containing `position`.
+    fn cell(&self, position: Vec3) -> IVec3 {
+        (position / self.cell_size).floor().as_ivec3()
+    }
+
+    /// Get every entry overlapping the cells between `min` and `max`, along
+    /// with every oversized entry, each entry reported once.
+    fn candidates(
+        &self,
+        min: Vec3,
+        max: Vec3,
+    ) -> impl Iterator<Item = &SpatialEntry> {
+        let (min_cell, max_cell) = (self.cell(min), self.cell(max));
+        let mut indices = Vec::new();
+
+        // Walk the cells in range, or the occupied cells if there are fewer
+        let occupied = i64::try_from(self.cells.len()).unwrap_or(i64::MAX);
+        if cell_count(min_cell, max_cell) <= occupied {
+            for x in min_cell.x..=max_cell.x {
+                for y in min_cell.y..=max_cell.y {
+                    for z in min_cell.z..=max_cell.z {
+                        if let Some(cell) = self.cells.get(&IVec3::new(x, y, z))
+                        {
+                            indices.extend_from_slice(cell);
+                        }
+                    }
+                }
+            }
+        } else {
+            for (key, cell) in &self.cells {
+                if key.cmpge(min_cell).all() && key.cmple(max_cell).all() {
+                    indices.extend_from_slice(cell);
+                }
+            }
+        }
+        indices.extend_from_slice(&self.oversized);
+
+        // Entries spanning several cells are collected once per cell
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices.into_iter().map(|index| &self.entries[index])
+    }
+}
+
+/// Get the number of grid cells from `min_cell` to `max_cell`, saturating
+/// instead of overflowing for huge ranges.
+fn cell_count(min_cell: IVec3, max_cell: IVec3) -> i64 {
+    let range = (max_cell.as_i64vec3() - min_cell.as_i64vec3() + I64Vec3::ONE)
+        .max(I64Vec3::ZERO);
+    range.x.saturating_mul(range.y).saturating_mul(range.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get the [`Entity`] with the given index.
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw(index)
+    }
+
+    /// Get an index with an entry of radius `0.5` every `spacing` meters
+    /// along the x axis.
+    fn line(count: u32, spacing: f32) -> SpatialIndex {
+        let mut index = SpatialIndex::new(1.0);
+        for i in 0..count {
+            #[allow(clippy::cast_precision_loss)]
+            let x = i as f32 * spacing;
+            index.insert(entity(i), Vec3::new(x, 0.0, 0.0), 0.5);
+        }
+        index
+    }
+
+    /// Sort entities so results can be compared regardless of their order.
+    fn sorted(mut entities: Vec<Entity>) -> Vec<Entity> {
+        entities.sort();
+        entities
+    }
+
+    #[test]
+    fn query_radius() {
+        let index = line(5, 2.0);
+
+        assert_eq!(
+            index.query_radius(Vec3::new(4.0, 0.0, 0.0), 1.0),
+            [entity(2)]
+        );
+        assert_eq!(
+            sorted(index.query_radius(Vec3::new(4.0, 0.0, 0.0), 1.6)),
+            [entity(1), entity(2), entity(3)]
+        );
+        assert!(index.query_radius(Vec3::new(3.0, 2.0, 0.0), 1.0).is_empty());
+    }
+
+    #[test]
+    fn query_aabb() {
+        let index = line(5, 2.0);
+
+        assert_eq!(
+            sorted(index.query_aabb(
+                Vec3::new(1.6, -1.0, -1.0),
+                Vec3::new(5.6, 1.0, 1.0),
+            )),
+            [entity(1), entity(2), entity(3)]
+        );
+        assert!(index
+            .query_aabb(Vec3::new(0.6, 1.0, -1.0), Vec3::new(1.4, 2.0, 1.0))
+            .is_empty());
+    }
+
+    #[test]
+    fn k_nearest() {
+        let index = line(10, 1.0);
+
+        assert_eq!(
+            index.k_nearest(Vec3::new(4.2, 0.0, 0.0), 3),
+            [entity(4), entity(5), entity(3)]
+        );
+        assert_eq!(index.k_nearest(Vec3::new(100.0, 0.0, 0.0), 20).len(), 10);
+        assert!(index.k_nearest(Vec3::new(4.2, 0.0, 0.0), 0).is_empty());
+        assert!(index.k_nearest(Vec3::NAN, 3).is_empty());
+    }
+
+    #[test]
+    fn overlapping_pairs_reported_once() {
+        // Entries spanning several cells, each overlapping its neighbours
+        let mut index = line(4, 0.9);
+
+        // A floor and a wall too large for the grid, overlapping each other
+        // and every entry
+        index.insert_aabb(
+            entity(10),
+            Vec3::new(0.0, -1.0, 0.0),
+            1000.0,
+            Vec3::new(-100.0, -2.0, -100.0),
+            Vec3::new(100.0, 0.0, 100.0),
+        );
+        index.insert_aabb(
+            entity(11),
+            Vec3::new(0.0, 0.0, -1.0),
+            1000.0,
+            Vec3::new(-100.0, -100.0, -2.0),
+            Vec3::new(100.0, 100.0, 0.0),
+        );
+
+        // A half-space is never paired
+        index.insert(entity(12), Vec3::ZERO, f32::INFINITY);
+
+        let mut pairs = index
+            .overlapping_pairs()
+            .into_iter()
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect::<Vec<_>>();
+        pairs.sort();
+
+        let mut expected = vec![
+            (entity(0), entity(1)),
+            (entity(1), entity(2)),
+            (entity(2), entity(3)),
+            (entity(10), entity(11)),
+        ];
+        for i in 0..4 {
+            expected.push((entity(i), entity(10)));
+            expected.push((entity(i), entity(11)));
+        }
+        expected.sort();
+
+        assert_eq!(pairs, expected);
+    }
+}
@@ -0,0 +1,51 @@
+//! The `sets` module contains the [`DynamicsSet`] used to order systems around
+//! the dynamics step.
+
+use bevy::prelude::*;
+
+/// The [`SystemSet`]s every system of the [`crate::DynamicsPlugin`] is placed
+/// in.
+///
+/// The sets run in the order they are declared, and every set except
+/// [`DynamicsSet::Interpolate`] and [`DynamicsSet::Debug`] runs on the schedule
+/// of the [`crate::DynamicsPlugin`], which is [`FixedUpdate`] by default. Add
+/// your own systems to a set, or order them before or after one, to run them at
+/// a specific point of the step.
+///
+/// The sets on the schedule of the [`crate::DynamicsPlugin`] after
+/// [`DynamicsSet::AdvanceTime`] are skipped while the
+/// [`crate::SimulationControl`] is paused, see [`crate::dynamics_running`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DynamicsSet {
+    /// Advances the [`Time`] clock of the [`crate::Dynamics`] context with
+    /// [`crate::update_dynamics_time`]. Runs every step, even while the
+    /// dynamics are paused.
+    AdvanceTime,
+    /// Prepares the state used during the step, such as recording the start
+    /// position of every [`crate::Ccd`] [`Entity`].
+    PrepareForces,
+    /// Accumulates the forces of the step. Systems adding to an
+    /// [`crate::ExternalForce`], [`crate::ExternalImpulse`] or
+    /// [`crate::ExternalTorque`] belong here.
+    ApplyForces,
+    /// Integrates the dynamics of every [`Entity`] with
+    /// [`crate::apply_dynamics`], which also solves every
+    /// [`crate::Constraint`] after each substep. Systems changing constraints
+    /// should run before this set.
+    Integrate,
+    /// Resolves collisions after integration with [`crate::apply_ccd`],
+    /// [`crate::update_spatial_index`] and [`crate::resolve_contacts`].
+    ResolveCollisions,
+    /// Writes back the final state of the step, such as keeping entities inside
+    /// their [`crate::WorldBounds`] with [`crate::apply_world_bounds`].
+    Writeback,
+    /// Blends the rendered [`Transform`] of entities with a
+    /// [`crate::TransformInterpolation`] or [`crate::TransformExtrapolation`]
+    /// on the [`PostUpdate`] schedule, before the [`Transform`] is propagated.
+    /// Only used when the [`crate::DynamicsPlugin`] uses the [`Time<Fixed>`]
+    /// time source.
+    Interpolate,
+    /// Draws the debug gizmos on the [`Update`] schedule, which is only used
+    /// when the `debug` feature is enabled.
+    Debug,
+}
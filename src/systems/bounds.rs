@@ -0,0 +1,136 @@
+//! The `bounds` module contains the [`apply_world_bounds`] system.
+
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::{
+    BoundsBehavior, Collider, OutOfBounds, RigidBodyMode, Velocity, WorldBounds,
+};
+
+/// Keeps entities with a [`Velocity`] inside their [`WorldBounds`].
+///
+/// Entities use their own [`WorldBounds`] component if present, otherwise the
+/// [`WorldBounds`] resource, and are left alone without either, like entities
+/// with a [`RigidBodyMode::Static`]. An [`OutOfBounds`] event is sent and
+/// triggered on every [`Entity`] leaving its bounds, before its
+/// [`BoundsBehavior`] is applied. Entities left outside with
+/// [`BoundsBehavior::Notify`] are only reported again once they came back
+/// inside.
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], after
+/// [`crate::resolve_contacts`].
+#[allow(clippy::type_complexity)]
+pub fn apply_world_bounds(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut Transform,
+        &mut Velocity,
+        Option<&Collider>,
+        Option<&WorldBounds>,
+        Option<&BoundsBehavior>,
+        Option<&RigidBodyMode>,
+    )>,
+    world_bounds: Option<Res<WorldBounds>>,
+    mut out_of_bounds: EventWriter<OutOfBounds>,
+    mut outside: Local<HashSet<Entity>>,
+) {
+    // Forget entities that are no longer simulated
+    outside.retain(|&entity| query.contains(entity));
+
+    for (
+        entity,
+        mut transform,
+        mut velocity,
+        collider,
+        bounds,
+        behavior,
+        mode,
+    ) in &mut query
+    {
+        // Static bodies never move
+        if mode == Some(&RigidBodyMode::Static) {
+            continue;
+        }
+
+        let Some(bounds) = bounds.or(world_bounds.as_deref()) else {
+            continue;
+        };
+
+        let radius = collider
+            .map(Collider::bounding_radius)
+            .filter(|radius| radius.is_finite())
+            .unwrap_or(0.0);
+        if bounds.contains(transform.translation, radius) {
+            outside.remove(&entity);
+            continue;
+        }
+
+        // Entities left outside are only reported when they leave, every
+        // other behavior brings them back inside
+        let behavior = behavior.copied().unwrap_or(bounds.behavior);
+        if behavior == BoundsBehavior::Notify {
+            if !outside.insert(entity) {
+                continue;
+            }
+        } else {
+            outside.remove(&entity);
+        }
+
+        let event = OutOfBounds {
+            entity,
+            position: transform.translation,
+            velocity: velocity.value,
+        };
+        out_of_bounds.send(event);
+        commands.trigger_targets(event, entity);
+
+        // The region the center of the entity has to stay within
+        let min = bounds.min + radius;
+        let max = (bounds.max - radius).max(min);
+
+        match behavior {
+            BoundsBehavior::Reflect { restitution } => {
+                for axis in 0..3 {
+                    let position = transform.translation[axis];
+                    if position < min[axis] {
+                        transform.translation[axis] =
+                            2.0f32.mul_add(min[axis], -position);
+                        velocity.value[axis] =
+                            velocity.value[axis].abs() * restitution;
+                    } else if position > max[axis] {
+                        transform.translation[axis] =
+                            2.0f32.mul_add(max[axis], -position);
+                        velocity.value[axis] =
+                            -velocity.value[axis].abs() * restitution;
+                    }
+                }
+                // Reflecting can overshoot the opposite side when far outside
+                transform.translation = transform.translation.clamp(min, max);
+            }
+            BoundsBehavior::Wrap => {
+                let size = max - min;
+                for axis in 0..3 {
+                    if size[axis] > 0.0 {
+                        transform.translation[axis] = min[axis]
+                            + (transform.translation[axis] - min[axis])
+                                .rem_euclid(size[axis]);
+                    }
+                }
+            }
+            BoundsBehavior::Clamp => {
+                for axis in 0..3 {
+                    let position = transform.translation[axis];
+                    if position < min[axis] || position > max[axis] {
+                        transform.translation[axis] =
+                            position.clamp(min[axis], max[axis]);
+                        velocity.value[axis] = 0.0;
+                    }
+                }
+            }
+            BoundsBehavior::Despawn => {
+                commands.entity(entity).despawn_recursive();
+            }
+            BoundsBehavior::Notify => {}
+        }
+    }
+}
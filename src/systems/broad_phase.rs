@@ -0,0 +1,39 @@
+//! The `broad_phase` module contains the [`update_spatial_index`] system.
+
+use bevy::prelude::*;
+
+use crate::{Collider, SpatialIndex, Velocity};
+
+/// Rebuilds the [`SpatialIndex`] from every [`Entity`] with a [`Velocity`] or
+/// a [`Collider`].
+///
+/// Entities are bounded by the [`Collider::bounding_radius`] and
+/// [`Collider::aabb`] of their [`Collider`], or stored as points without one.
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], after
+/// [`crate::apply_dynamics`].
+#[allow(clippy::type_complexity)]
+pub fn update_spatial_index(
+    mut index: ResMut<SpatialIndex>,
+    query: Query<
+        (Entity, &Transform, Option<&Collider>),
+        Or<(With<Velocity>, With<Collider>)>,
+    >,
+) {
+    index.clear();
+
+    for (entity, transform, collider) in &query {
+        if let Some(collider) = collider {
+            let (min, max) = collider.aabb(transform);
+            index.insert_aabb(
+                entity,
+                transform.translation,
+                collider.bounding_radius(),
+                min,
+                max,
+            );
+        } else {
+            index.insert(entity, transform.translation, 0.0);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! The `ccd` module contains the [`record_ccd_start`] and [`apply_ccd`]
+//! systems.
+
+use bevy::prelude::*;
+
+use super::effective_time_scale;
+use crate::{
+    signed_distance, Ccd, Collider, Dynamics, LocalTimeScale, Restitution,
+    RigidBodyMode, Sensor, TimeDilationVolume, Velocity,
+};
+
+/// Maximum number of conservative advancement iterations per sweep.
+const MAX_SWEEP_ITERATIONS: usize = 32;
+
+/// Distance at which a sweep is considered to have hit a surface.
+const SWEEP_TOLERANCE: f32 = 1e-3;
+
+/// Records the translation of every [`Ccd`] [`Entity`] at the start of the
+/// step.
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], before
+/// [`crate::apply_dynamics`].
+pub fn record_ccd_start(mut query: Query<(&mut Ccd, &Transform)>) {
+    for (mut ccd, transform) in &mut query {
+        ccd.start = transform.translation;
+    }
+}
+
+/// Sweeps every [`Ccd`] [`Entity`] along its motion during the step against
+/// every static [`Collider`].
+///
+/// The sweep goes from the position at the start of the step to the position
+/// after integration. Static colliders are those without a [`Velocity`] or
+/// with a [`RigidBodyMode::Static`].
+///
+/// On impact the [`Entity`] is moved back to the time of impact, its
+/// [`Velocity`] is reflected about the surface normal using the average
+/// [`Restitution`], and it travels the remainder of the step along the
+/// reflected velocity, over the step scaled by its [`LocalTimeScale`] and
+/// every [`TimeDilationVolume`] containing its start. Only the first impact
+/// of a step is handled, any further overlap is left to
+/// [`crate::resolve_contacts`].
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], after
+/// [`crate::apply_dynamics`].
+#[allow(clippy::type_complexity)]
+pub fn apply_ccd(
+    mut query: Query<(
+        &Ccd,
+        &mut Transform,
+        &mut Velocity,
+        Option<&Collider>,
+        Option<&Restitution>,
+        Option<&RigidBodyMode>,
+        Option<&LocalTimeScale>,
+    )>,
+    statics: Query<
+        (
+            &Collider,
+            &Transform,
+            Option<&Restitution>,
+            Has<Velocity>,
+            Option<&RigidBodyMode>,
+        ),
+        (Without<Ccd>, Without<Sensor>),
+    >,
+    volumes: Query<
+        (&TimeDilationVolume, &Collider, &Transform),
+        Without<Velocity>,
+    >,
+    time: Res<Time<Dynamics>>,
+) {
+    for (
+        ccd,
+        mut transform,
+        mut velocity,
+        collider,
+        restitution,
+        mode,
+        local_time_scale,
+    ) in &mut query
+    {
+        // Only dynamic bodies bounce off static colliders
+        if !mode.copied().unwrap_or_default().is_dynamic() {
+            continue;
+        }
+
+        let start = ccd.start;
+        let motion = transform.translation - start;
+        let distance = motion.length();
+        let radius = collider
+            .map(Collider::bounding_radius)
+            .filter(|radius| radius.is_finite())
+            .unwrap_or(0.0);
+
+        // Slow entities are handled by the regular contact step
+        if distance <= radius.max(SWEEP_TOLERANCE) {
+            continue;
+        }
+
+        // Bounds of the swept path, used to skip distant static colliders
+        let min = start.min(transform.translation) - radius;
+        let max = start.max(transform.translation) + radius;
+
+        // Find the earliest time of impact along the sweep
+        let mut earliest: Option<(f32, Vec3, Option<&Restitution>)> = None;
+        for (
+            static_collider,
+            static_transform,
+            static_restitution,
+            has_velocity,
+            mode,
+        ) in &statics
+        {
+            // Only sweep against colliders that never move
+            if has_velocity && mode != Some(&RigidBodyMode::Static) {
+                continue;
+            }
+
+            let center = static_transform.translation;
+            if center.clamp(min, max).distance(center)
+                > static_collider.bounding_radius()
+            {
+                continue;
+            }
+
+            let hit = sweep_sphere(
+                start,
+                motion,
+                radius,
+                static_collider,
+                static_transform,
+            );
+            if let Some((time_of_impact, normal)) = hit {
+                if earliest
+                    .is_none_or(|(earliest, ..)| time_of_impact < earliest)
+                {
+                    earliest =
+                        Some((time_of_impact, normal, static_restitution));
+                }
+            }
+        }
+
+        let Some((time_of_impact, normal, static_restitution)) = earliest
+        else {
+            continue;
+        };
+
+        // Reflect the velocity about the surface normal
+        let restitution = 0.5
+            * (restitution.map_or(0.0, |r| r.value)
+                + static_restitution.map_or(0.0, |r| r.value));
+        let normal_speed = velocity.value.dot(normal);
+        if normal_speed < 0.0 {
+            velocity.value -= (1.0 + restitution) * normal_speed * normal;
+        }
+
+        // Move to the time of impact, and use the rest of the step along the
+        // reflected velocity
+        let delta_time_secs = time.delta_secs()
+            * effective_time_scale(local_time_scale, start, &volumes);
+        transform.translation = start
+            + motion * time_of_impact
+            + velocity.value * delta_time_secs * (1.0 - time_of_impact);
+    }
+}
+
+/// Sweep a sphere from `start` along `motion` against a [`Collider`] using
+/// conservative advancement, returning the fraction of the motion at the time
+/// of impact and the surface normal.
+///
+/// Returns [`None`] if the sphere does not hit the collider, or if it already
+/// overlaps it at the start.
+fn sweep_sphere(
+    start: Vec3,
+    motion: Vec3,
+    radius: f32,
+    collider: &Collider,
+    transform: &Transform,
+) -> Option<(f32, Vec3)> {
+    let length = motion.length();
+    let mut time = 0.0;
+
+    for i in 0..MAX_SWEEP_ITERATIONS {
+        let (distance, normal) =
+            signed_distance(collider, transform, start + motion * time);
+        let distance = distance - radius;
+
+        if distance < 0.0 && i == 0 {
+            return None;
+        }
+        if distance <= SWEEP_TOLERANCE {
+            return Some((time, normal));
+        }
+
+        // The sphere can safely advance by the distance to the surface
+        time += distance / length;
+        if time > 1.0 {
+            return None;
+        }
+    }
+
+    None
+}
@@ -0,0 +1,265 @@
+//! The `collision` module contains the [`resolve_contacts`] system.
+
+use bevy::{ecs::query::QueryData, prelude::*, utils::HashSet};
+
+use crate::{
+    contact::contact, AngularVelocity, Collider, CollisionEnded,
+    CollisionPersisting, CollisionStarted, Contact, Friction, Inertia,
+    InverseMass, Restitution, RigidBodyMode, Sensor, SpatialIndex, Velocity,
+};
+
+/// The components of an [`Entity`] used to resolve contacts.
+///
+/// Entities without a [`Collider`] are only used as points to detect them
+/// entering a [`Sensor`].
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct ContactBody {
+    entity: Entity,
+    collider: Option<&'static Collider>,
+    transform: &'static mut Transform,
+    velocity: Option<&'static mut Velocity>,
+    inverse_mass: Option<&'static InverseMass>,
+    angular_velocity: Option<&'static mut AngularVelocity>,
+    inertia: Option<&'static Inertia>,
+    restitution: Option<&'static Restitution>,
+    friction: Option<&'static Friction>,
+    mode: Option<&'static RigidBodyMode>,
+    sensor: Has<Sensor>,
+}
+
+impl ContactBodyItem<'_> {
+    /// Whether the body never moves, either without a [`Velocity`] or with a
+    /// [`RigidBodyMode::Static`].
+    fn is_static(&self) -> bool {
+        self.velocity.is_none() || self.mode == Some(&RigidBodyMode::Static)
+    }
+
+    /// Get the inverse mass, which is `0.0` for static bodies without a
+    /// [`Velocity`] and for bodies that are not [`RigidBodyMode::Dynamic`].
+    fn inverse_mass(&self) -> f32 {
+        if self.velocity.is_some() {
+            self.mode
+                .copied()
+                .unwrap_or_default()
+                .inverse_mass(self.inverse_mass)
+        } else {
+            0.0
+        }
+    }
+
+    /// Get the inverse inertia tensor in world space, which is zero for
+    /// bodies that can not rotate or are not [`RigidBodyMode::Dynamic`].
+    fn inverse_inertia(&self) -> Mat3 {
+        if self.inverse_mass() <= 0.0 {
+            return Mat3::ZERO;
+        }
+
+        match (&self.velocity, &self.angular_velocity, self.inertia) {
+            (Some(_), Some(_), Some(inertia)) => {
+                inertia.inverse_world(self.transform.rotation)
+            }
+            _ => Mat3::ZERO,
+        }
+    }
+
+    /// Get the velocity of the point at `offset` from the center of mass.
+    fn point_velocity(&self, offset: Vec3) -> Vec3 {
+        let linear = self.velocity.as_ref().map_or(Vec3::ZERO, |v| v.value);
+        let angular = self
+            .angular_velocity
+            .as_ref()
+            .map_or(Vec3::ZERO, |angular_velocity| angular_velocity.value);
+        linear + angular.cross(offset)
+    }
+
+    /// Apply an `impulse` at `offset` from the center of mass.
+    fn apply_impulse(&mut self, impulse: Vec3, offset: Vec3) {
+        let inverse_mass = self.inverse_mass();
+        let inverse_inertia = self.inverse_inertia();
+
+        if let Some(velocity) = self.velocity.as_mut() {
+            velocity.value += impulse * inverse_mass;
+        }
+        if let Some(angular_velocity) = self.angular_velocity.as_mut() {
+            angular_velocity.value += inverse_inertia * offset.cross(impulse);
+        }
+    }
+}
+
+/// Resolves contacts between [`Collider`]s after integration.
+///
+/// Candidate pairs are taken from the [`SpatialIndex`], along with every
+/// unbounded collider such as a half-space paired with every other collider.
+///
+/// Overlapping colliders are pushed apart in proportion to their
+/// [`InverseMass`], and an impulse is applied along the contact normal using
+/// the average [`Restitution`] of both colliders, along with a friction
+/// impulse limited by their average [`Friction`]. Colliders without a
+/// [`Velocity`] or with a [`RigidBodyMode::Static`] are static and never move,
+/// and colliders with a [`RigidBodyMode::Kinematic`] push others without being
+/// pushed back.
+/// Contacts involving a [`Sensor`] are only reported, and never resolved, and
+/// also include entities with a [`Velocity`] but without a [`Collider`].
+///
+/// A [`CollisionStarted`], [`CollisionPersisting`] or [`CollisionEnded`] event
+/// is sent for every touching pair, and triggered on both entities.
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], after
+/// [`crate::update_spatial_index`].
+pub fn resolve_contacts(
+    mut commands: Commands,
+    mut query: Query<ContactBody>,
+    index: Res<SpatialIndex>,
+    mut touching: Local<HashSet<(Entity, Entity)>>,
+    mut started: EventWriter<CollisionStarted>,
+    mut persisting: EventWriter<CollisionPersisting>,
+    mut ended: EventWriter<CollisionEnded>,
+) {
+    let unbounded_pairs = index.unbounded().iter().flat_map(|&unbounded| {
+        index
+            .entries()
+            .iter()
+            .map(move |entry| (unbounded, entry.entity))
+    });
+
+    let mut previously_touching = std::mem::take(&mut *touching);
+    for (a, b) in index.overlapping_pairs().into_iter().chain(unbounded_pairs) {
+        // Order the pair so it can be tracked across steps
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+
+        let Ok([mut body_a, mut body_b]) = query.get_many_mut([a, b]) else {
+            continue;
+        };
+
+        // Static bodies never need to be resolved against each other
+        if body_a.is_static() && body_b.is_static() {
+            continue;
+        }
+
+        // Entities without a collider can only enter sensors, as points
+        let point = Collider::sphere(0.0);
+        let (collider_a, collider_b) = match (body_a.collider, body_b.collider)
+        {
+            (Some(a), Some(b)) => (a, b),
+            (Some(a), None) if body_a.sensor => (a, &point),
+            (None, Some(b)) if body_b.sensor => (&point, b),
+            _ => continue,
+        };
+
+        let Some(contact) = contact(
+            collider_a,
+            &body_a.transform,
+            collider_b,
+            &body_b.transform,
+        ) else {
+            continue;
+        };
+
+        let impulse = if body_a.sensor || body_b.sensor {
+            0.0
+        } else {
+            resolve_contact(&mut body_a, &mut body_b, &contact)
+        };
+
+        touching.insert((a, b));
+        if previously_touching.remove(&(a, b)) {
+            let event = CollisionPersisting {
+                a,
+                b,
+                contact,
+                impulse,
+            };
+            persisting.send(event);
+            commands.trigger_targets(event, [a, b]);
+        } else {
+            let event = CollisionStarted {
+                a,
+                b,
+                contact,
+                impulse,
+            };
+            started.send(event);
+            commands.trigger_targets(event, [a, b]);
+        }
+    }
+
+    // Pairs that touched last step but not anymore have ended
+    for (a, b) in previously_touching {
+        let event = CollisionEnded { a, b };
+        ended.send(event);
+        commands.trigger_targets(event, [a, b]);
+    }
+}
+
+/// Resolve a single [`Contact`] between two bodies, returning the magnitude of
+/// the normal impulse applied.
+fn resolve_contact(
+    a: &mut ContactBodyItem,
+    b: &mut ContactBodyItem,
+    contact: &Contact,
+) -> f32 {
+    let inverse_mass_a = a.inverse_mass();
+    let inverse_mass_b = b.inverse_mass();
+    let total_inverse_mass = inverse_mass_a + inverse_mass_b;
+    if total_inverse_mass <= 0.0 {
+        return 0.0;
+    }
+
+    // Push the bodies apart in proportion to their inverse mass
+    let correction = contact.normal * contact.depth / total_inverse_mass;
+    a.transform.translation -= correction * inverse_mass_a;
+    b.transform.translation += correction * inverse_mass_b;
+
+    let offset_a = contact.point - a.transform.translation;
+    let offset_b = contact.point - b.transform.translation;
+    let inverse_inertia_a = a.inverse_inertia();
+    let inverse_inertia_b = b.inverse_inertia();
+
+    // Effective inverse mass of the contact along a direction
+    let effective_inverse_mass = |direction: Vec3| {
+        let angular_a =
+            (inverse_inertia_a * offset_a.cross(direction)).cross(offset_a);
+        let angular_b =
+            (inverse_inertia_b * offset_b.cross(direction)).cross(offset_b);
+        total_inverse_mass + direction.dot(angular_a + angular_b)
+    };
+
+    let relative_velocity =
+        b.point_velocity(offset_b) - a.point_velocity(offset_a);
+    let normal_speed = relative_velocity.dot(contact.normal);
+
+    // Bodies that are already separating need no impulse
+    if normal_speed >= 0.0 {
+        return 0.0;
+    }
+
+    let restitution = 0.5
+        * (a.restitution.map_or(0.0, |r| r.value)
+            + b.restitution.map_or(0.0, |r| r.value));
+    let normal_impulse = -(1.0 + restitution) * normal_speed
+        / effective_inverse_mass(contact.normal);
+    a.apply_impulse(-contact.normal * normal_impulse, offset_a);
+    b.apply_impulse(contact.normal * normal_impulse, offset_b);
+
+    // Apply friction along the remaining tangential velocity
+    let relative_velocity =
+        b.point_velocity(offset_b) - a.point_velocity(offset_a);
+    let tangential_velocity = relative_velocity
+        - contact.normal * relative_velocity.dot(contact.normal);
+    if let Some(tangent) = tangential_velocity.try_normalize() {
+        let friction = 0.5
+            * (a.friction
+                .map_or_else(|| Friction::default().value, |f| f.value)
+                + b.friction
+                    .map_or_else(|| Friction::default().value, |f| f.value));
+        let max_friction_impulse = friction * normal_impulse;
+        let friction_impulse = (-relative_velocity.dot(tangent)
+            / effective_inverse_mass(tangent))
+        .clamp(-max_friction_impulse, max_friction_impulse);
+        a.apply_impulse(-tangent * friction_impulse, offset_a);
+        b.apply_impulse(tangent * friction_impulse, offset_b);
+    }
+
+    normal_impulse
+}
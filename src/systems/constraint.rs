@@ -0,0 +1,188 @@
+//! The `constraint` module contains the extended position based dynamics
+//! (XPBD) solver of every [`Constraint`], run by [`crate::apply_dynamics`].
+
+use bevy::prelude::*;
+
+use super::effective_time_scale;
+use crate::{
+    Collider, Constraint, ConstraintKind, InverseMass, LocalTimeScale,
+    RigidBodyMode, TimeDilationVolume, Velocity,
+};
+
+/// The gradient of a [`Constraint`] for each of its two ends, along with the
+/// inverse mass of the end.
+type ConstraintTerms = [(Entity, Vec3, f32); 2];
+
+/// Solves every [`Constraint`] after integration over a step of
+/// `delta_time_secs`, correcting the position and [`Velocity`] of the
+/// constrained `bodies`.
+///
+/// Entities in `statics`, and bodies that are not [`RigidBodyMode::Dynamic`],
+/// are used as fixed ends with an infinite mass.
+///
+/// The [`Velocity`] correction of each body uses its own step, scaled by its
+/// [`LocalTimeScale`] and the `volumes` containing it.
+#[allow(clippy::type_complexity)]
+pub fn solve_constraints(
+    constraints: &Query<&Constraint>,
+    bodies: &mut Query<(
+        &mut Transform,
+        &mut Velocity,
+        Option<&LocalTimeScale>,
+    )>,
+    statics: &Query<&Transform, Without<Velocity>>,
+    masses: &Query<(Option<&InverseMass>, Option<&RigidBodyMode>)>,
+    volumes: &Query<
+        (&TimeDilationVolume, &Collider, &Transform),
+        Without<Velocity>,
+    >,
+    iterations: u32,
+    delta_time_secs: f32,
+) {
+    if constraints.is_empty() || delta_time_secs <= 0.0 {
+        return;
+    }
+
+    // The Lagrange multiplier of every constraint, accumulated over the
+    // iterations
+    let mut lambdas = vec![0.0; constraints.iter().len()];
+
+    for _ in 0..iterations {
+        for (constraint, lambda) in constraints.iter().zip(&mut lambdas) {
+            let Some((value, terms)) = evaluate(&constraint.kind, |entity| {
+                body_state(bodies, statics, masses, entity)
+            }) else {
+                continue;
+            };
+
+            let total_inverse_mass: f32 = terms
+                .iter()
+                .map(|(_, gradient, inverse_mass)| {
+                    inverse_mass * gradient.length_squared()
+                })
+                .sum();
+            let compliance =
+                constraint.compliance / (delta_time_secs * delta_time_secs);
+            if total_inverse_mass + compliance <= 0.0 {
+                continue;
+            }
+
+            let delta_lambda = compliance.mul_add(-*lambda, -value)
+                / (total_inverse_mass + compliance);
+            *lambda += delta_lambda;
+
+            // Move each entity along its gradient, and correct its velocity
+            // to match over its own step
+            for (entity, gradient, inverse_mass) in terms {
+                let correction = gradient * inverse_mass * delta_lambda;
+                if let Ok((mut transform, mut velocity, local_time_scale)) =
+                    bodies.get_mut(entity)
+                {
+                    let delta_time_secs = delta_time_secs
+                        * effective_time_scale(
+                            local_time_scale,
+                            transform.translation,
+                            volumes,
+                        );
+                    transform.translation += correction;
+                    if delta_time_secs > 0.0 {
+                        velocity.value += correction / delta_time_secs;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Get the position and inverse mass of an [`Entity`], if it still exists.
+#[allow(clippy::type_complexity)]
+fn body_state(
+    bodies: &Query<(&mut Transform, &mut Velocity, Option<&LocalTimeScale>)>,
+    statics: &Query<&Transform, Without<Velocity>>,
+    masses: &Query<(Option<&InverseMass>, Option<&RigidBodyMode>)>,
+    entity: Entity,
+) -> Option<(Vec3, f32)> {
+    if let Ok((transform, ..)) = bodies.get(entity) {
+        let inverse_mass = masses.get(entity).map_or(1.0, |(mass, mode)| {
+            mode.copied().unwrap_or_default().inverse_mass(mass)
+        });
+        Some((transform.translation, inverse_mass))
+    } else {
+        statics
+            .get(entity)
+            .ok()
+            .map(|transform| (transform.translation, 0.0))
+    }
+}
+
+/// Evaluate a [`ConstraintKind`] and its gradient for each entity, given the
+/// position and inverse mass of each [`Entity`] from `state`.
+///
+/// Returns [`None`] if an [`Entity`] no longer exists, the gradient is
+/// undefined, or an inequality constraint is already satisfied.
+fn evaluate(
+    kind: &ConstraintKind,
+    state: impl Fn(Entity) -> Option<(Vec3, f32)>,
+) -> Option<(f32, ConstraintTerms)> {
+    match *kind {
+        ConstraintKind::Distance {
+            first,
+            second,
+            length,
+        }
+        | ConstraintKind::MaxDistance {
+            first,
+            second,
+            length,
+        } => {
+            let (first_position, first_inverse_mass) = state(first)?;
+            let (second_position, second_inverse_mass) = state(second)?;
+
+            let offset = second_position - first_position;
+            let direction = offset.try_normalize()?;
+
+            let value = offset.length() - length;
+            if matches!(kind, ConstraintKind::MaxDistance { .. })
+                && value <= 0.0
+            {
+                return None;
+            }
+
+            Some((
+                value,
+                [
+                    (first, -direction, first_inverse_mass),
+                    (second, direction, second_inverse_mass),
+                ],
+            ))
+        }
+        ConstraintKind::Attachment { entity, point } => {
+            let (position, inverse_mass) = state(entity)?;
+
+            let offset = position - point;
+            let direction = offset.try_normalize()?;
+
+            Some((
+                offset.length(),
+                [(entity, direction, inverse_mass), (entity, Vec3::ZERO, 0.0)],
+            ))
+        }
+        ConstraintKind::Plane {
+            entity,
+            normal,
+            distance,
+        } => {
+            let (position, inverse_mass) = state(entity)?;
+
+            let value = position.dot(*normal) - distance;
+            if value >= 0.0 {
+                return None;
+            }
+
+            Some((
+                value,
+                [(entity, *normal, inverse_mass), (entity, Vec3::ZERO, 0.0)],
+            ))
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! The `debug` module contains the [`debug`] system, which is only available
+//! when the `debug` feature is enabled.
+
+use bevy::prelude::*;
+
+use crate::{Acceleration, Debug, DebugColors, DebugScale, Velocity};
+
+/// Debugs the [`Acceleration`] and [`Velocity`] components by drawing arrows
+/// in the scene with the Bevy [`Gizmos`].
+pub fn debug(
+    mut gizmos: Gizmos,
+    query: Query<(
+        &Transform,
+        &Velocity,
+        &Acceleration,
+        &Debug,
+        &DebugColors,
+        &DebugScale,
+    )>,
+) {
+    for (transform, velocity, acceleration, debug, colors, scale) in
+        query.iter()
+    {
+        if debug.velocity {
+            gizmos.arrow(
+                transform.translation,
+                transform.translation + velocity.value * scale.scale,
+                colors.velocity,
+            );
+        }
+
+        if debug.acceleration {
+            gizmos.arrow(
+                transform.translation,
+                transform.translation + acceleration.value * scale.scale,
+                colors.acceleration,
+            );
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! The `divergence` module contains the [`Divergence`] detection and recovery
+//! of entities whose state stopped being finite, run by
+//! [`crate::apply_dynamics`].
+
+use bevy::{ecs::system::SystemParam, prelude::*, utils::HashMap};
+
+use crate::{
+    AngularVelocity, DivergencePolicy, DynamicsConfig, DynamicsDiverged,
+    Velocity,
+};
+
+/// Bevy [`SystemParam`] detecting and recovering entities whose position,
+/// rotation or velocity stopped being finite.
+///
+/// Used by [`crate::apply_dynamics`] after the integration of every substep,
+/// so a diverged [`Entity`] is recovered before it reaches its neighbours
+/// through constraints, springs or contacts.
+#[derive(SystemParam)]
+pub struct Divergence<'w, 's> {
+    config: Res<'w, DynamicsConfig>,
+    commands: Commands<'w, 's>,
+    events: EventWriter<'w, DynamicsDiverged>,
+    last_good: Local<'s, HashMap<Entity, BodyState>>,
+}
+
+impl Divergence<'_, '_> {
+    /// Send and trigger a [`DynamicsDiverged`] event on every body whose
+    /// state stopped being finite, and recover it with the
+    /// [`DivergencePolicy`] of the [`DynamicsConfig`].
+    ///
+    /// Bodies are recovered using the last state they had at the end of a
+    /// substep where every value was finite. Despawned bodies are reset to
+    /// that state as well, so they stay harmless until the despawn is
+    /// applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the [`DivergencePolicy::Panic`] in debug builds.
+    pub fn recover(
+        &mut self,
+        mut bodies: Query<(
+            Entity,
+            &mut Transform,
+            &mut Velocity,
+            Option<&mut AngularVelocity>,
+        )>,
+    ) {
+        // Forget the last good state of entities that are no longer simulated
+        self.last_good.retain(|&entity, _| bodies.contains(entity));
+
+        for (entity, mut transform, mut velocity, mut angular_velocity) in
+            &mut bodies
+        {
+            let state = BodyState::new(
+                &transform,
+                &velocity,
+                angular_velocity.as_deref(),
+            );
+            if state.is_finite() {
+                self.last_good.insert(entity, state);
+                continue;
+            }
+
+            let event = state.diverged(entity);
+            self.events.send(event);
+            self.commands.trigger_targets(event, entity);
+
+            let last_good = self.last_good.get(&entity).copied();
+            let recovered = state
+                .recover(
+                    entity,
+                    self.config.divergence_policy,
+                    last_good.as_ref(),
+                )
+                .unwrap_or_else(|| {
+                    self.commands.entity(entity).despawn_recursive();
+                    last_good.unwrap_or_else(|| state.or(BodyState::REST))
+                });
+            recovered.apply(
+                &mut transform,
+                &mut velocity,
+                angular_velocity.as_deref_mut(),
+            );
+            self.last_good.insert(entity, recovered);
+        }
+    }
+}
+
+/// The state of a body checked for divergence at the end of every substep.
+///
+/// The last finite state of every body is kept by [`Divergence`] to recover
+/// it with the [`DivergencePolicy`].
+#[derive(Clone, Copy, Debug)]
+pub struct BodyState {
+    translation: Vec3,
+    rotation: Quat,
+    velocity: Vec3,
+    angular_velocity: Vec3,
+}
+
+impl BodyState {
+    /// A body at rest at the origin, used to replace non-finite values
+    /// without a previous finite state.
+    const REST: Self = Self {
+        translation: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        velocity: Vec3::ZERO,
+        angular_velocity: Vec3::ZERO,
+    };
+
+    /// Get the current state of a body.
+    fn new(
+        transform: &Transform,
+        velocity: &Velocity,
+        angular_velocity: Option<&AngularVelocity>,
+    ) -> Self {
+        Self {
+            translation: transform.translation,
+            rotation: transform.rotation,
+            velocity: velocity.value,
+            angular_velocity: angular_velocity
+                .map_or(Vec3::ZERO, |angular_velocity| angular_velocity.value),
+        }
+    }
+
+    /// Whether every value of the state is finite.
+    fn is_finite(&self) -> bool {
+        self.translation.is_finite()
+            && self.rotation.is_finite()
+            && self.velocity.is_finite()
+            && self.angular_velocity.is_finite()
+    }
+
+    /// Get the [`DynamicsDiverged`] event of this state for an [`Entity`].
+    const fn diverged(&self, entity: Entity) -> DynamicsDiverged {
+        DynamicsDiverged {
+            entity,
+            position: self.translation,
+            rotation: self.rotation,
+            velocity: self.velocity,
+            angular_velocity: self.angular_velocity,
+        }
+    }
+
+    /// Replace every non-finite value with the one of the `fallback` state.
+    fn or(self, fallback: Self) -> Self {
+        Self {
+            translation: if self.translation.is_finite() {
+                self.translation
+            } else {
+                fallback.translation
+            },
+            rotation: if self.rotation.is_finite() {
+                self.rotation
+            } else {
+                fallback.rotation
+            },
+            velocity: if self.velocity.is_finite() {
+                self.velocity
+            } else {
+                fallback.velocity
+            },
+            angular_velocity: if self.angular_velocity.is_finite() {
+                self.angular_velocity
+            } else {
+                fallback.angular_velocity
+            },
+        }
+    }
+
+    /// Get the state to recover to with the [`DivergencePolicy`], given the
+    /// `last_good` state of the body if it was ever finite.
+    ///
+    /// Returns [`None`] if the body should be despawned instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the [`DivergencePolicy::Panic`] in debug builds.
+    fn recover(
+        self,
+        entity: Entity,
+        policy: DivergencePolicy,
+        last_good: Option<&Self>,
+    ) -> Option<Self> {
+        let reset =
+            || last_good.copied().unwrap_or_else(|| self.or(Self::REST));
+
+        match policy {
+            DivergencePolicy::ResetToLastGood => Some(reset()),
+            DivergencePolicy::ZeroVelocity => Some(Self {
+                velocity: Vec3::ZERO,
+                angular_velocity: Vec3::ZERO,
+                ..self.or(last_good.copied().unwrap_or(Self::REST))
+            }),
+            DivergencePolicy::Despawn => None,
+            DivergencePolicy::Panic => {
+                #[allow(clippy::manual_assert)]
+                if cfg!(debug_assertions) {
+                    panic!("The dynamics of {entity} diverged: {self:?}");
+                }
+                Some(reset())
+            }
+        }
+    }
+
+    /// Write the state back to a body.
+    const fn apply(
+        &self,
+        transform: &mut Transform,
+        velocity: &mut Velocity,
+        angular_velocity: Option<&mut AngularVelocity>,
+    ) {
+        transform.translation = self.translation;
+        transform.rotation = self.rotation;
+        velocity.value = self.velocity;
+        if let Some(angular_velocity) = angular_velocity {
+            angular_velocity.value = self.angular_velocity;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::{Collider, DynamicsPlugin, Inertia, Mass};
+
+    /// The entities that triggered a [`DynamicsDiverged`] event.
+    #[derive(Resource, Default)]
+    struct Diverged(Vec<Entity>);
+
+    #[test]
+    fn diverged_body_does_not_affect_neighbours() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            DynamicsPlugin::default()
+                .with_divergence_policy(DivergencePolicy::Despawn),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(
+            Duration::from_secs_f64(1.0 / 64.0),
+        ));
+        app.init_resource::<Diverged>();
+        app.add_observer(
+            |trigger: Trigger<DynamicsDiverged>,
+             mut diverged: ResMut<Diverged>| {
+                diverged.0.push(trigger.entity());
+            },
+        );
+
+        // A sphere spinning at a non-finite rate, touched by a healthy one
+        let sphere = || {
+            (
+                Collider::sphere(0.5),
+                Mass::new(1.0),
+                Inertia::solid_sphere(1.0, 0.5),
+            )
+        };
+        let broken = app
+            .world_mut()
+            .spawn((
+                sphere(),
+                Transform::default(),
+                Velocity::default(),
+                AngularVelocity::new(Vec3::NAN),
+            ))
+            .id();
+        let healthy = app
+            .world_mut()
+            .spawn((
+                sphere(),
+                Transform::from_xyz(0.9, 0.0, 0.0),
+                Velocity::new(Vec3::new(-1.0, 0.0, 0.0)),
+                AngularVelocity::default(),
+            ))
+            .id();
+
+        for _ in 0..8 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<Diverged>().0, [broken]);
+        assert!(app.world().get_entity(broken).is_err());
+
+        let transform = app.world().get::<Transform>(healthy).unwrap();
+        let velocity = app.world().get::<Velocity>(healthy).unwrap();
+        let angular_velocity =
+            app.world().get::<AngularVelocity>(healthy).unwrap();
+        assert!(transform.translation.is_finite());
+        assert!(transform.rotation.is_finite());
+        assert!(velocity.value.is_finite());
+        assert!(angular_velocity.value.is_finite());
+    }
+}
@@ -0,0 +1,434 @@
+//! The `dynamics` module contains the [`apply_dynamics`] system.
+
+use bevy::{
+    ecs::{query::QueryItem, system::SystemParam},
+    prelude::*,
+};
+
+use super::{
+    effective_time_scale, solve_constraints, spring_links, Divergence,
+    SpringLink,
+};
+use crate::{
+    Acceleration, AngularAcceleration, AngularDamping, AngularVelocity,
+    Collider, Constraint, Damping, Drag, Dynamics, DynamicsConfig,
+    ExternalForce, ExternalImpulse, ExternalTorque, ForceField, Gravity,
+    GravityScale, Inertia, Integrator, InverseMass, LocalTimeScale,
+    RigidBodyMode, Spring, TimeDilationVolume, Velocity,
+};
+
+/// Applies [`Acceleration`], [`ExternalForce`], [`Damping`], and [`Velocity`]
+/// changes in a single pass.
+///
+/// Each step is divided into the number of substeps set in the
+/// [`DynamicsConfig`], which are integrated one after the other.
+///
+/// The [`ExternalForce`] accumulated during the step is converted to
+/// acceleration using the [`InverseMass`] of the [`Entity`], acts during
+/// every substep, and is cleared afterwards. The [`ExternalImpulse`] is
+/// applied to the [`Velocity`] once per step before integration, and cleared
+/// as well.
+///
+/// The [`Gravity`] resource is applied on top of the [`Acceleration`], scaled
+/// by the [`GravityScale`] of the [`Entity`] if present.
+///
+/// The forces of every [`Spring`], [`ForceField`] and [`Drag`] depend on the
+/// position and velocity, so they are evaluated by the [`Integrator`] at every
+/// intermediate state of the substep, and converted to acceleration using the
+/// [`InverseMass`]. The other end of each [`Spring`] is held at its state at
+/// the start of the substep.
+///
+/// Entities with an [`AngularVelocity`] also have their rotation integrated
+/// from their [`AngularAcceleration`], [`ExternalTorque`] and
+/// [`AngularDamping`].
+///
+/// Position and velocity are advanced with the [`Integrator`] component of the
+/// [`Entity`] if present, otherwise with the [`DynamicsConfig`] integrator.
+///
+/// The step of each [`Entity`] is scaled by its [`LocalTimeScale`], and by the
+/// scale of every [`TimeDilationVolume`] containing it.
+///
+/// Entities with a [`RigidBodyMode::Kinematic`] are only moved by their
+/// [`Velocity`] and [`AngularVelocity`], and entities with a
+/// [`RigidBodyMode::Static`] are not integrated at all. Both keep their place
+/// in springs and constraints with an infinite mass.
+///
+/// After the integration of each substep, a [`crate::DynamicsDiverged`] event
+/// is sent and triggered on every [`Entity`] whose position, rotation or
+/// velocity stopped being finite, which is then recovered with the
+/// [`crate::DivergencePolicy`] of the [`DynamicsConfig`] before it can affect
+/// its neighbours.
+///
+/// Every [`Constraint`] is then solved after each substep, with the number of
+/// iterations set in the [`DynamicsConfig`].
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`].
+#[allow(clippy::type_complexity)]
+pub fn apply_dynamics(
+    mut query: Query<(
+        Entity,
+        &mut Transform,
+        &mut Velocity,
+        Option<&Acceleration>,
+        Option<&Damping>,
+        Option<&Integrator>,
+        Option<&mut ExternalForce>,
+        Option<&mut ExternalImpulse>,
+        Option<&GravityScale>,
+        Option<AngularQueryData>,
+        Option<&Drag>,
+        Option<&ForceField>,
+        Option<&InverseMass>,
+        Option<&LocalTimeScale>,
+        Option<&RigidBodyMode>,
+    )>,
+    joints: Joints,
+    volumes: Query<
+        (&TimeDilationVolume, &Collider, &Transform),
+        Without<Velocity>,
+    >,
+    config: Res<DynamicsConfig>,
+    gravity: Res<Gravity>,
+    time: Res<Time<Dynamics>>,
+    mut divergence: Divergence,
+) {
+    let substeps = config.substeps.max(1);
+    #[allow(clippy::cast_precision_loss)]
+    let delta_time_secs = time.delta_secs() / substeps as f32;
+
+    for _ in 0..substeps {
+        let spring_links = spring_links(
+            &joints.springs,
+            &query.transmute_lens().query(),
+            &joints.statics,
+        );
+
+        for (
+            entity,
+            mut transform,
+            mut velocity,
+            acceleration,
+            damping,
+            entity_integrator,
+            external_force,
+            external_impulse,
+            gravity_scale,
+            angular,
+            drag,
+            force_field,
+            inverse_mass,
+            local_time_scale,
+            mode,
+        ) in &mut query
+        {
+            // Scale the substep by the local time scale and every volume the
+            // entity is inside
+            let delta_time_secs = delta_time_secs
+                * effective_time_scale(
+                    local_time_scale,
+                    transform.translation,
+                    &volumes,
+                );
+
+            match mode.copied().unwrap_or_default() {
+                RigidBodyMode::Dynamic => {}
+                RigidBodyMode::Kinematic => {
+                    // Only move along the velocity, ignoring every force
+                    transform.translation += velocity.value * delta_time_secs;
+                    if let Some((angular_velocity, ..)) = angular {
+                        angular_velocity.integrate_rotation(
+                            &mut transform.rotation,
+                            delta_time_secs,
+                        );
+                    }
+                    continue;
+                }
+                RigidBodyMode::Static => continue,
+            }
+
+            let integrator =
+                entity_integrator.copied().unwrap_or(config.integrator);
+            let inverse_mass =
+                InverseMass(RigidBodyMode::Dynamic.inverse_mass(inverse_mass));
+            let mut acceleration = acceleration
+                .map_or(Vec3::ZERO, |acceleration| acceleration.value);
+
+            // Apply gravity independently of the entity's own acceleration
+            acceleration +=
+                gravity.0 * gravity_scale.map_or(1.0, |scale| scale.0);
+
+            // Convert the accumulated force to acceleration
+            if let Some(force) = external_force {
+                acceleration += force.acceleration(&inverse_mass);
+            }
+
+            // Apply the accumulated impulse and clear it, so it only applies
+            // during the first substep
+            if let Some(mut impulse) = external_impulse {
+                impulse.consume(&mut velocity, &inverse_mass);
+            }
+
+            // Advance position and velocity with the selected integrator,
+            // evaluating the forces depending on them at every intermediate
+            // state
+            let forces = StateForces {
+                links: spring_links.get(&entity).map_or(&[], Vec::as_slice),
+                force_field,
+                drag,
+                rotation: transform.rotation,
+            };
+            integrator.step(
+                &mut transform.translation,
+                &mut velocity.value,
+                delta_time_secs,
+                |position, velocity| {
+                    acceleration
+                        + forces.force(position, velocity) * inverse_mass.0
+                },
+            );
+            velocity.clamp_to_speed_of_light();
+
+            // Apply damping if component exists
+            if let Some(damping) = damping {
+                velocity.apply_damping(damping, delta_time_secs);
+            }
+
+            // Apply angular dynamics if the entity can rotate
+            if let Some(angular) = angular {
+                apply_angular_dynamics(
+                    &mut transform.rotation,
+                    angular,
+                    delta_time_secs,
+                );
+            }
+        }
+
+        divergence.recover(query.transmute_lens().query());
+
+        solve_constraints(
+            &joints.constraints,
+            &mut query.transmute_lens().query(),
+            &joints.statics,
+            &joints.masses,
+            &volumes,
+            config.constraint_iterations,
+            delta_time_secs,
+        );
+    }
+
+    // Clear the accumulated forces, impulses and torques after the last
+    // substep, including those of bodies that ignored them
+    clear_accumulators(query.transmute_lens().query());
+}
+
+/// The [`Spring`]s and [`Constraint`]s linking the bodies integrated by
+/// [`apply_dynamics`], along with the entities they can be attached to.
+#[derive(SystemParam)]
+pub struct Joints<'w, 's> {
+    springs: Query<'w, 's, &'static Spring>,
+    constraints: Query<'w, 's, &'static Constraint>,
+    statics: Query<'w, 's, &'static Transform, Without<Velocity>>,
+    masses: Query<
+        'w,
+        's,
+        (Option<&'static InverseMass>, Option<&'static RigidBodyMode>),
+    >,
+}
+
+/// The forces on an [`Entity`] depending on its position and velocity.
+struct StateForces<'a> {
+    /// Every [`Spring`] attached to the [`Entity`].
+    links: &'a [SpringLink],
+    /// The [`ForceField`] of the [`Entity`], if any.
+    force_field: Option<&'a ForceField>,
+    /// The [`Drag`] of the [`Entity`], if any.
+    drag: Option<&'a Drag>,
+    /// The rotation of the [`Entity`], which the [`Drag`] depends on.
+    rotation: Quat,
+}
+
+impl StateForces<'_> {
+    /// Get the total force at `position` when moving at `velocity`.
+    fn force(&self, position: Vec3, velocity: Vec3) -> Vec3 {
+        let mut force = self
+            .links
+            .iter()
+            .map(|link| link.force(position, velocity))
+            .sum::<Vec3>();
+        if let Some(force_field) = self.force_field {
+            force += force_field.force(position, velocity);
+        }
+        if let Some(drag) = self.drag {
+            force += drag.force(velocity, self.rotation);
+        }
+        force
+    }
+}
+
+/// Clear the [`ExternalForce`], [`ExternalImpulse`] and [`ExternalTorque`]
+/// accumulated during the step.
+#[allow(clippy::type_complexity)]
+fn clear_accumulators(
+    mut query: Query<(
+        Option<&mut ExternalForce>,
+        Option<&mut ExternalImpulse>,
+        Option<&mut ExternalTorque>,
+    )>,
+) {
+    for (external_force, external_impulse, external_torque) in &mut query {
+        if let Some(mut external_force) = external_force {
+            external_force.clear();
+        }
+        if let Some(mut external_impulse) = external_impulse {
+            external_impulse.value = Vec3::ZERO;
+        }
+        if let Some(mut external_torque) = external_torque {
+            external_torque.clear();
+        }
+    }
+}
+
+/// The angular components queried by [`apply_dynamics`].
+type AngularQueryData = (
+    &'static mut AngularVelocity,
+    Option<&'static AngularAcceleration>,
+    Option<&'static Inertia>,
+    Option<&'static mut ExternalTorque>,
+    Option<&'static AngularDamping>,
+);
+
+/// Applies [`AngularAcceleration`], [`ExternalTorque`], [`AngularDamping`],
+/// and [`AngularVelocity`] changes to the `rotation`.
+///
+/// The [`ExternalTorque`] is converted to angular acceleration using the world
+/// space [`Inertia`] tensor, including the gyroscopic term `ω × Iω`. The
+/// angular velocity is advanced first and then used to integrate the rotation
+/// quaternion, which is renormalised.
+fn apply_angular_dynamics(
+    rotation: &mut Quat,
+    (
+        mut angular_velocity,
+        angular_acceleration,
+        inertia,
+        external_torque,
+        angular_damping,
+    ): QueryItem<AngularQueryData>,
+    delta_time_secs: f32,
+) {
+    let mut acceleration = angular_acceleration
+        .map_or(Vec3::ZERO, |angular_acceleration| {
+            angular_acceleration.value
+        });
+
+    if let Some(inertia) = inertia {
+        let mut torque = -angular_velocity
+            .value
+            .cross(inertia.world(*rotation) * angular_velocity.value);
+
+        // Convert the accumulated torque to acceleration
+        if let Some(external_torque) = external_torque {
+            torque += external_torque.value;
+        }
+
+        acceleration += inertia.inverse_world(*rotation) * torque;
+    }
+
+    angular_velocity.value += acceleration * delta_time_secs;
+
+    // Apply angular damping if component exists
+    if let Some(angular_damping) = angular_damping {
+        angular_velocity
+            .apply_angular_damping(angular_damping, delta_time_secs);
+    }
+
+    angular_velocity.integrate_rotation(rotation, delta_time_secs);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::{DynamicsPlugin, Mass};
+
+    /// The number of steps simulated, ten seconds at the default fixed rate.
+    const STEPS: usize = 640;
+
+    /// Simulate `x'' = -x` starting at rest at `x = 1` through
+    /// [`apply_dynamics`], with the force added to the body by `attach`,
+    /// returning the error from `x(t) = cos(t)`.
+    fn oscillator_error(
+        integrator: Integrator,
+        attach: impl FnOnce(&mut World, Entity),
+    ) -> f32 {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, DynamicsPlugin::default()));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(
+            Duration::from_secs_f64(1.0 / 64.0),
+        ));
+
+        let body = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(1.0, 0.0, 0.0),
+                Velocity::default(),
+                Mass::new(1.0),
+                integrator,
+            ))
+            .id();
+        attach(app.world_mut(), body);
+
+        for _ in 0..STEPS {
+            app.update();
+        }
+
+        let position = app.world().get::<Transform>(body).unwrap().translation;
+        let time = app.world().resource::<Time<Dynamics>>().elapsed_secs();
+        position.distance(Vec3::new(time.cos(), 0.0, 0.0))
+    }
+
+    /// The maximum error of each scheme on the oscillator after ten seconds.
+    const OSCILLATOR_ERRORS: [(Integrator, f32); 5] = [
+        (Integrator::ExplicitEuler, 1e-1),
+        (Integrator::SemiImplicitEuler, 5e-3),
+        (Integrator::VelocityVerlet, 1e-4),
+        (Integrator::Leapfrog, 1e-4),
+        (Integrator::Rk4, 5e-6),
+    ];
+
+    #[test]
+    fn force_field_oscillator_drift() {
+        for (integrator, max_error) in OSCILLATOR_ERRORS {
+            let error = oscillator_error(integrator, |world, body| {
+                world
+                    .entity_mut(body)
+                    .insert(ForceField::new(|position, _| -position));
+            });
+            assert!(
+                error < max_error,
+                "{integrator:?} drifted by {error}, more than {max_error}"
+            );
+        }
+    }
+
+    #[test]
+    fn spring_oscillator_drift() {
+        for (integrator, max_error) in OSCILLATOR_ERRORS {
+            // A unit stiffness spring anchored far along the axis of motion
+            let error = oscillator_error(integrator, |world, body| {
+                world.spawn(Spring::anchored(
+                    body,
+                    Vec3::new(-10.0, 0.0, 0.0),
+                    10.0,
+                    1.0,
+                ));
+            });
+            assert!(
+                error < max_error,
+                "{integrator:?} drifted by {error}, more than {max_error}"
+            );
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! The `interpolation` module contains the systems smoothing the rendered
+//! [`Transform`] of entities with a [`TransformInterpolation`] or
+//! [`TransformExtrapolation`].
+
+use bevy::prelude::*;
+
+use super::effective_time_scale;
+use crate::{
+    AngularVelocity, Collider, Dynamics, LocalTimeScale, TimeDilationVolume,
+    TransformExtrapolation, TransformInterpolation, Velocity,
+};
+
+/// Restores the simulated [`Transform`] of every [`TransformInterpolation`]
+/// and [`TransformExtrapolation`] [`Entity`] before a step.
+///
+/// A [`Transform`] changed by another system since it was rendered is kept
+/// as is, and becomes the start of the blending. The blending systems do the
+/// same for changes made on frames without a step.
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], at the
+/// start of the [`crate::DynamicsSet::PrepareForces`] set.
+pub fn restore_transforms(
+    mut interpolated: Query<(&mut Transform, &mut TransformInterpolation)>,
+    mut extrapolated: Query<
+        (&mut Transform, &mut TransformExtrapolation),
+        Without<TransformInterpolation>,
+    >,
+) {
+    for (mut transform, mut interpolation) in &mut interpolated {
+        let current = restore(
+            &mut transform,
+            interpolation.current,
+            interpolation.rendered,
+        );
+        interpolation.previous = Some(current);
+        interpolation.current = Some(current);
+        interpolation.rendered = Some(current);
+    }
+
+    for (mut transform, mut extrapolation) in &mut extrapolated {
+        let current = restore(
+            &mut transform,
+            extrapolation.current,
+            extrapolation.rendered,
+        );
+        extrapolation.current = Some(current);
+        extrapolation.rendered = Some(current);
+    }
+}
+
+/// Records the simulated [`Transform`] of every [`TransformInterpolation`] and
+/// [`TransformExtrapolation`] [`Entity`] after a step.
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], at the
+/// end of the [`crate::DynamicsSet::Writeback`] set.
+pub fn record_transforms(
+    mut interpolated: Query<(&Transform, &mut TransformInterpolation)>,
+    mut extrapolated: Query<(&Transform, &mut TransformExtrapolation)>,
+) {
+    for (transform, mut interpolation) in &mut interpolated {
+        interpolation.current = Some(*transform);
+        interpolation.rendered = Some(*transform);
+    }
+
+    for (transform, mut extrapolation) in &mut extrapolated {
+        extrapolation.current = Some(*transform);
+        extrapolation.rendered = Some(*transform);
+    }
+}
+
+/// Blends the [`Transform`] of every [`TransformInterpolation`] [`Entity`]
+/// between its previous and current step, using
+/// [`Time<Fixed>::overstep_fraction`].
+///
+/// While the [`Time<Dynamics>`] clock does not advance, such as when the
+/// [`crate::SimulationControl`] is paused, the current step is rendered as
+/// is.
+///
+/// This system is run on the [`PostUpdate`] schedule, in the
+/// [`crate::DynamicsSet::Interpolate`] set, only when the
+/// [`crate::DynamicsPlugin`] uses the [`Time<Fixed>`] time source.
+pub fn interpolate_transforms(
+    mut query: Query<(&mut Transform, &mut TransformInterpolation)>,
+    time: Res<Time<Fixed>>,
+    dynamics_time: Res<Time<Dynamics>>,
+) {
+    let fraction = time.overstep_fraction();
+    let frozen = dynamics_time.delta().is_zero();
+
+    for (mut transform, mut interpolation) in &mut query {
+        // Start blending from a transform changed by another system
+        if interpolation.rendered != Some(*transform) {
+            interpolation.previous = Some(*transform);
+            interpolation.current = Some(*transform);
+            interpolation.rendered = Some(*transform);
+            continue;
+        }
+
+        // Hold the current step while the dynamics do not advance
+        if frozen {
+            interpolation.previous = interpolation.current;
+        }
+
+        let (Some(previous), Some(current)) =
+            (interpolation.previous, interpolation.current)
+        else {
+            continue;
+        };
+
+        transform.translation =
+            previous.translation.lerp(current.translation, fraction);
+        transform.rotation =
+            previous.rotation.slerp(current.rotation, fraction);
+        interpolation.rendered = Some(*transform);
+    }
+}
+
+/// Predicts the [`Transform`] of every [`TransformExtrapolation`] [`Entity`]
+/// ahead of its current step.
+///
+/// The prediction follows its [`Velocity`] and [`AngularVelocity`] over
+/// [`Time<Fixed>::overstep_fraction`] of the last step, scaled by its
+/// [`LocalTimeScale`] and every [`TimeDilationVolume`] containing it.
+///
+/// This system is run on the [`PostUpdate`] schedule, in the
+/// [`crate::DynamicsSet::Interpolate`] set, only when the
+/// [`crate::DynamicsPlugin`] uses the [`Time<Fixed>`] time source.
+#[allow(clippy::type_complexity)]
+pub fn extrapolate_transforms(
+    mut query: Query<
+        (
+            &mut Transform,
+            &mut TransformExtrapolation,
+            Option<&Velocity>,
+            Option<&AngularVelocity>,
+            Option<&LocalTimeScale>,
+        ),
+        (Without<TransformInterpolation>, Without<TimeDilationVolume>),
+    >,
+    volumes: Query<
+        (&TimeDilationVolume, &Collider, &Transform),
+        Without<Velocity>,
+    >,
+    time: Res<Time<Fixed>>,
+    dynamics_time: Res<Time<Dynamics>>,
+) {
+    let ahead = time.overstep_fraction() * dynamics_time.delta_secs();
+
+    for (
+        mut transform,
+        mut extrapolation,
+        velocity,
+        angular_velocity,
+        local_time_scale,
+    ) in &mut query
+    {
+        // Start predicting from a transform changed by another system
+        if extrapolation.rendered != Some(*transform) {
+            extrapolation.current = Some(*transform);
+            extrapolation.rendered = Some(*transform);
+            continue;
+        }
+
+        let Some(current) = extrapolation.current else {
+            continue;
+        };
+        let ahead = ahead
+            * effective_time_scale(
+                local_time_scale,
+                current.translation,
+                &volumes,
+            );
+
+        transform.translation = current.translation
+            + velocity.map_or(Vec3::ZERO, |velocity| velocity.value * ahead);
+        transform.rotation = current.rotation;
+        if let Some(angular_velocity) = angular_velocity {
+            angular_velocity.integrate_rotation(&mut transform.rotation, ahead);
+        }
+        extrapolation.rendered = Some(*transform);
+    }
+}
+
+/// Restore the simulated `current` [`Transform`] if the `transform` is still
+/// the one that was `rendered`, returning the simulated [`Transform`].
+fn restore(
+    transform: &mut Transform,
+    current: Option<Transform>,
+    rendered: Option<Transform>,
+) -> Transform {
+    if let Some(current) = current {
+        if rendered == Some(*transform) {
+            *transform = current;
+        }
+    }
+    *transform
+}
@@ -0,0 +1,24 @@
+//! The `mass` module contains the [`update_inverse_mass`] system.
+
+use bevy::prelude::*;
+
+use crate::{InverseMass, Mass};
+
+/// Validates every changed [`Mass`], and keeps its cached [`InverseMass`] in
+/// sync.
+///
+/// Invalid masses set after insertion are replaced with the default mass with
+/// a logged error, the same way they are when the [`Mass`] is inserted.
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], in
+/// the [`crate::DynamicsSet::PrepareForces`] set.
+pub fn update_inverse_mass(
+    mut query: Query<(Entity, &mut Mass, &mut InverseMass), Changed<Mass>>,
+) {
+    for (entity, mut mass, mut inverse_mass) in &mut query {
+        if !mass.is_valid() {
+            mass.validate(entity);
+        }
+        inverse_mass.set_if_neq(InverseMass(mass.inverse()));
+    }
+}
@@ -0,0 +1,51 @@
+//! The `systems` module contains the systems that are used by the
+//! [`crate::DynamicsPlugin`].
+//!
+//! Systems such as:
+//! - [`update_dynamics_time`] advancing the clock of the dynamics, along with
+//!   the [`dynamics_running`] condition.
+//! - [`apply_dynamics`] integrating every entity, including every
+//!   [`crate::Spring`] and [`crate::Constraint`], and recovering entities whose
+//!   state stopped being finite with the [`Divergence`] system parameter.
+//! - [`update_inverse_mass`] keeping every [`crate::InverseMass`] in sync with
+//!   its [`crate::Mass`].
+//! - [`update_spatial_index`] maintaining the [`crate::SpatialIndex`] broad
+//!   phase.
+//! - [`record_ccd_start`] and [`apply_ccd`] preventing fast [`crate::Ccd`]
+//!   entities from tunneling.
+//! - [`resolve_contacts`] resolving contacts between [`crate::Collider`]s.
+//! - [`apply_world_bounds`] keeping entities inside their
+//!   [`crate::WorldBounds`].
+//! - [`restore_transforms`], [`record_transforms`],
+//!   [`interpolate_transforms`] and [`extrapolate_transforms`] smoothing the
+//!   rendered `Transform` between steps.
+//!
+//! But also the [`debug`] system hidden behind the `debug` feature flag.
+
+mod bounds;
+mod broad_phase;
+mod ccd;
+mod collision;
+mod constraint;
+#[cfg(feature = "debug")]
+mod debug;
+mod divergence;
+mod dynamics;
+mod interpolation;
+mod mass;
+mod spring;
+mod time;
+
+pub use bounds::*;
+pub use broad_phase::*;
+pub use ccd::*;
+pub use collision::*;
+pub use constraint::*;
+#[cfg(feature = "debug")]
+pub use debug::*;
+pub use divergence::*;
+pub use dynamics::*;
+pub use interpolation::*;
+pub use mass::*;
+pub use spring::*;
+pub use time::*;
@@ -0,0 +1,87 @@
+//! The `spring` module contains the evaluation of every [`Spring`], run by
+//! [`crate::apply_dynamics`] at every substep.
+
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{Spring, SpringAnchor, Velocity};
+
+/// A [`Spring`] seen from one of the bodies it is attached to, with the other
+/// end held at its state at the start of the substep.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpringLink {
+    /// The linking [`Spring`].
+    pub spring: Spring,
+    /// Whether the body is the first end of the [`Spring`].
+    pub first: bool,
+    /// The position of the other end.
+    pub position: Vec3,
+    /// The velocity of the other end.
+    pub velocity: Vec3,
+}
+
+impl SpringLink {
+    /// Get the force of the [`Spring`] on the body at `position` moving at
+    /// `velocity`.
+    pub fn force(&self, position: Vec3, velocity: Vec3) -> Vec3 {
+        if self.first {
+            self.spring
+                .force(position, velocity, self.position, self.velocity)
+        } else {
+            -self
+                .spring
+                .force(self.position, self.velocity, position, velocity)
+        }
+    }
+}
+
+/// Get the [`SpringLink`] of every [`Spring`] attached to each of the
+/// `bodies`.
+///
+/// Entities in `statics` are used as fixed ends, and have no links.
+pub fn spring_links(
+    springs: &Query<&Spring>,
+    bodies: &Query<(&Transform, &Velocity)>,
+    statics: &Query<&Transform, Without<Velocity>>,
+) -> HashMap<Entity, Vec<SpringLink>> {
+    // Get the position and velocity of an end, if it still exists
+    let state = |anchor: SpringAnchor| match anchor {
+        SpringAnchor::Entity(entity) => bodies
+            .get(entity)
+            .map(|(transform, velocity)| {
+                (transform.translation, velocity.value)
+            })
+            .or_else(|_| {
+                statics
+                    .get(entity)
+                    .map(|transform| (transform.translation, Vec3::ZERO))
+            })
+            .ok(),
+        SpringAnchor::Point(point) => Some((point, Vec3::ZERO)),
+    };
+
+    // Collect the links per entity, as several springs may share an end
+    let mut links = HashMap::<Entity, Vec<SpringLink>>::default();
+    for spring in springs {
+        let (Some(first), Some(second)) =
+            (state(spring.first), state(spring.second))
+        else {
+            continue;
+        };
+
+        for (anchor, first, (position, velocity)) in
+            [(spring.first, true, second), (spring.second, false, first)]
+        {
+            if let SpringAnchor::Entity(entity) = anchor {
+                if bodies.contains(entity) {
+                    links.entry(entity).or_default().push(SpringLink {
+                        spring: *spring,
+                        first,
+                        position,
+                        velocity,
+                    });
+                }
+            }
+        }
+    }
+    links
+}
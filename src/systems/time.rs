@@ -0,0 +1,74 @@
+//! The `time` module contains the [`update_dynamics_time`] system, the
+//! [`dynamics_running`] run condition, and the [`effective_time_scale`] of
+//! each [`Entity`].
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    signed_distance, Collider, Dynamics, LocalTimeScale, SimulationControl,
+    TimeDilationVolume, Velocity,
+};
+
+/// Advances the `Time<Dynamics>` [`Resource`] by the delta of the `Time<T>`
+/// time source of the [`crate::DynamicsPlugin`], scaled by the
+/// [`SimulationControl`] time scale.
+///
+/// While the [`SimulationControl`] is paused the delta is zero, unless a
+/// pending step is consumed. A negative, NaN or infinite time scale also
+/// gives a delta of zero.
+///
+/// This system is run on the schedule of the [`crate::DynamicsPlugin`], in
+/// the [`crate::DynamicsSet::AdvanceTime`] set.
+pub fn update_dynamics_time<T: Default + Send + Sync + 'static>(
+    mut time: ResMut<Time<Dynamics>>,
+    mut control: ResMut<SimulationControl>,
+    source: Res<Time<T>>,
+) {
+    let delta = if control.advance() {
+        // The time scale can be set directly, so invalid scales are rejected
+        // here rather than panicking
+        Duration::try_from_secs_f64(
+            source.delta().as_secs_f64() * f64::from(control.time_scale),
+        )
+        .unwrap_or(Duration::ZERO)
+    } else {
+        Duration::ZERO
+    };
+    time.advance_by(delta);
+}
+
+/// Run condition which is `true` when the `Time<Dynamics>` [`Resource`]
+/// advanced during this step.
+///
+/// Every [`crate::DynamicsSet`] on the schedule of the
+/// [`crate::DynamicsPlugin`] only runs when this is `true`, so systems in them
+/// are skipped while the dynamics are paused.
+pub fn dynamics_running(time: Res<Time<Dynamics>>) -> bool {
+    !time.delta().is_zero()
+}
+
+/// Get the factor the step of an [`Entity`] at `position` is scaled by, from
+/// its [`LocalTimeScale`] and the scale of every [`TimeDilationVolume`]
+/// containing it.
+///
+/// The factor is never negative, and is used wherever the dynamics depend on
+/// the delta of the step of a single [`Entity`].
+pub fn effective_time_scale(
+    local_time_scale: Option<&LocalTimeScale>,
+    position: Vec3,
+    volumes: &Query<
+        (&TimeDilationVolume, &Collider, &Transform),
+        Without<Velocity>,
+    >,
+) -> f32 {
+    local_time_scale.map_or(1.0, |scale| scale.0).max(0.0)
+        * volumes
+            .iter()
+            .filter(|(_, collider, transform)| {
+                signed_distance(collider, transform, position).0 <= 0.0
+            })
+            .map(|(volume, ..)| volume.scale.max(0.0))
+            .product::<f32>()
+}